@@ -14,58 +14,153 @@ export type AaasContract = {
   },
   "instructions": [
     {
-      name: "claimChallenge";
-      discriminator: [132, 167, 9, 45, 203, 244, 30, 171];
-      accounts: [
+      "name": "batchVoteForVoteBasedChallenge",
+      "discriminator": [23, 19, 98, 235, 188, 245, 208, 242],
+      "accounts": [
         {
-          name: "signer";
-          writable: true;
-          signer: true;
+          "name": "signer",
+          "signer": true
         },
         {
-          name: "challengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
+          "name": "voter"
+        },
+        {
+          "name": "sessionKeyAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 101, 115, 115, 105, 111, 110, 95, 107, 101, 121]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voterChallengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "votes",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "VoteEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimChallenge",
+      "discriminator": [132, 167, 9, 45, 203, 244, 30, 171],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
-                kind: "const";
-                value: [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
               },
               {
-                kind: "arg";
-                path: "challengeId";
+                "kind": "arg",
+                "path": "challengeId"
               }
-            ];
-          };
+            ]
+          }
         },
         {
-          name: "mint";
+          "name": "mint",
+          "optional": true
         },
         {
-          name: "treasuryAccount";
-          writable: true;
+          "name": "treasuryAccount",
+          "writable": true
         },
         {
           "name": "userTokenAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -84,38 +179,9 @@ export type AaasContract = {
             "program": {
               "kind": "const",
               "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
               ]
             }
           }
@@ -126,1110 +192,3641 @@ export type AaasContract = {
           "pda": {
             "seeds": [
               {
-                kind: "const";
-                value: [117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116];
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
               },
               {
-                kind: "account";
-                path: "signer";
+                "kind": "account",
+                "path": "signer"
               }
-            ];
-          };
+            ]
+          }
         },
         {
-          name: "userChallengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
-                kind: "const";
-                value: [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
               },
               {
-                kind: "account";
-                path: "signer";
+                "kind": "account",
+                "path": "signer"
               },
               {
-                kind: "account";
-                path: "challengeAccount";
+                "kind": "account",
+                "path": "challengeAccount"
               }
-            ];
-          };
+            ]
+          }
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          name: "tokenProgram";
+          "name": "tokenProgram",
+          "optional": true
         }
-      ];
-      args: [
+      ],
+      "args": [
         {
-          name: "challengeId";
-          type: "u64";
-        }
-      ];
-    },
-    {
-      name: "initialize";
-      discriminator: [175, 175, 109, 31, 13, 152, 155, 237];
-      accounts: [
-        {
-          name: "state";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  112,
-                  114,
-                  111,
-                  103,
-                  114,
-                  97,
-                  109,
-                  95,
-                  111,
-                  119,
-                  110,
-                  101,
-                  114
-                ];
-              }
-            ];
-          };
-        },
-        {
-          name: "signer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          "name": "challengeId",
+          "type": "u64"
         }
-      ];
-      args: [];
-    },
-    {
-      name: "initializeChallenge";
-      discriminator: [131, 92, 76, 227, 13, 71, 164, 243];
-      accounts: [
-        {
-          name: "signer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "challengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "arg";
-                path: "challengeId";
-              }
-            ];
-          };
-        },
-        {
-          name: "mint";
-        },
-        {
-          name: "treasuryAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "arg";
-                path: "challengeId";
-              }
-            ];
-          };
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        },
-        {
-          name: "tokenProgram";
-        }
-      ];
-      args: [
+      ]
+    },
+    {
+      "name": "claimFees",
+      "discriminator": [82, 251, 233, 156, 12, 52, 184, 202],
+      "accounts": [
         {
-          name: "challengeId";
-          type: "u64";
+          "name": "signer",
+          "writable": true,
+          "signer": true
         },
         {
-          name: "challengeType";
-          type: {
-            defined: {
-              name: "challengeType";
-            };
-          };
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
         },
         {
-          name: "challengeName";
-          type: "string";
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
         },
         {
-          name: "challengeDescription";
-          type: "string";
+          "name": "mint",
+          "optional": true
         },
         {
-          name: "startTime";
-          type: "i64";
+          "name": "treasuryAccount",
+          "writable": true
         },
         {
-          name: "endTime";
-          type: "i64";
+          "name": "feeRecipient",
+          "writable": true
         },
         {
-          name: "moneyPerParticipant";
-          type: "u64";
+          "name": "feeRecipientTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeRecipient"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
         },
         {
-          name: "isPrivate";
-          type: "bool";
+          "name": "creator",
+          "writable": true
         },
         {
-          name: "privateGroup";
-          type: {
-            vec: "pubkey";
-          };
-        }
-      ];
-    },
-    {
-      name: "joinChallenge";
-      discriminator: [41, 104, 214, 73, 32, 168, 76, 79];
-      accounts: [
-        {
-          name: "signer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "challengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "arg";
-                path: "challengeId";
-              }
-            ];
-          };
-        },
-        {
-          name: "mint";
-        },
-        {
-          name: "treasuryAccount";
-          writable: true;
-        },
-        {
-          name: "userTokenAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "account";
-                path: "signer";
-              },
-              {
-                kind: "account";
-                path: "tokenProgram";
-              },
-              {
-                kind: "account";
-                path: "mint";
-              }
-            ];
-            program: {
-              kind: "const";
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ];
-            };
-          };
-        },
-        {
-          name: "userAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116];
-              },
-              {
-                kind: "account";
-                path: "signer";
-              }
-            ];
-          };
-        },
-        {
-          name: "userChallengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "account";
-                path: "signer";
-              },
-              {
-                kind: "account";
-                path: "challengeAccount";
-              }
-            ];
-          };
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        },
-        {
-          name: "tokenProgram";
-        }
-      ];
-      args: [
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
         {
-          name: "challengeId";
-          type: "u64";
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          name: "userName";
-          type: "string";
+          "name": "tokenProgram",
+          "optional": true
         },
         {
-          name: "description";
-          type: "string";
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
-      ];
-    },
-    {
-      name: "updateChallengeStatus";
-      discriminator: [193, 81, 19, 183, 19, 104, 28, 125];
-      accounts: [
-        {
-          name: "signer";
-          writable: true;
-          signer: true;
-        },
-        {
-          name: "state";
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  112,
-                  114,
-                  111,
-                  103,
-                  114,
-                  97,
-                  109,
-                  95,
-                  111,
-                  119,
-                  110,
-                  101,
-                  114
-                ];
-              }
-            ];
-          };
-        },
-        {
-          name: "challengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "arg";
-                path: "challengeId";
-              }
-            ];
-          };
-        },
-        {
-          name: "userChallengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
-              },
-              {
-                kind: "arg";
-                path: "userAddress";
-              },
-              {
-                kind: "account";
-                path: "challengeAccount";
-              }
-            ];
-          };
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
         }
-      ];
-      args: [
+      ]
+    },
+    {
+      "name": "closeChallenge",
+      "discriminator": [29, 156, 109, 17, 41, 99, 71, 236],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "feeRecipient",
+          "writable": true
+        },
+        {
+          "name": "feeRecipientTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeRecipient"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
         {
-          name: "challengeId";
-          type: "u64";
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          name: "userAddress";
-          type: "pubkey";
+          "name": "tokenProgram",
+          "optional": true
         },
         {
-          name: "challengeVerification";
-          type: {
-            defined: {
-              name: "challengeVerification";
-            };
-          };
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
         }
-      ];
-    },
-    {
-      name: "voteForVoteBasedChallenge";
-      discriminator: [175, 201, 246, 165, 25, 22, 42, 199];
-      accounts: [
+      ]
+    },
+    {
+      "name": "closeSponsor",
+      "discriminator": [123, 224, 123, 144, 26, 249, 25, 187],
+      "accounts": [
         {
-          name: "signer";
-          writable: true;
-          signer: true;
+          "name": "signer",
+          "signer": true
         },
         {
-          name: "challengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
-                kind: "const";
-                value: [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
               },
               {
-                kind: "arg";
-                path: "challengeId";
+                "kind": "arg",
+                "path": "challengeId"
               }
-            ];
-          };
+            ]
+          }
         },
         {
-          name: "userChallengeAccount";
-          writable: true;
-          pda: {
-            seeds: [
+          "name": "sponsorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
-                kind: "const";
-                value: [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ];
+                "kind": "const",
+                "value": [
+                  115, 112, 111, 110, 115, 111, 114, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
               },
               {
-                kind: "arg";
-                path: "userAddress";
+                "kind": "account",
+                "path": "challengeAccount"
               },
               {
-                kind: "account";
-                path: "challengeAccount";
+                "kind": "arg",
+                "path": "sponsorAddress"
               }
-            ];
-          };
+            ]
+          }
+        },
+        {
+          "name": "sponsor",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "sponsorAddress",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "closeUserChallenge",
+      "discriminator": [143, 84, 3, 170, 225, 177, 255, 243],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
         },
         {
-          name: "voteAccount";
-          writable: true;
-          pda: {
-            seeds: [
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
               {
-                kind: "const";
-                value: [118, 111, 116, 101, 95, 97, 99, 99, 111, 117, 110, 116];
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
               },
               {
-                kind: "account";
-                path: "challengeAccount";
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
               },
               {
-                kind: "account";
-                path: "signer";
+                "kind": "arg",
+                "path": "userAddress"
               },
               {
-                kind: "arg";
-                path: "userAddress";
+                "kind": "account",
+                "path": "challengeAccount"
               }
-            ];
-          };
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
         }
-      ];
-      args: [
+      ],
+      "args": [
         {
-          name: "challengeId";
-          type: "u64";
+          "name": "challengeId",
+          "type": "u64"
         },
         {
-          name: "userAddress";
-          type: "pubkey";
+          "name": "userAddress",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "createSessionKey",
+      "discriminator": [137, 204, 246, 242, 200, 143, 215, 56],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
         },
         {
-          name: "challengeVerification";
-          type: {
-            defined: {
-              name: "challengeVerificationType";
-            };
-          };
-        }
-      ];
-    }
-  ];
-  accounts: [
+          "name": "sessionKeyAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 101, 115, 115, 105, 111, 110, 95, 107, 101, 121]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "drawJury",
+      "discriminator": [192, 82, 81, 195, 206, 241, 173, 90],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "expireDispute",
+      "discriminator": [241, 116, 178, 182, 234, 173, 61, 120],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "disputeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100, 105, 115, 112, 117, 116, 101, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "userAddress",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "finalizeChallenge",
+      "discriminator": [184, 38, 132, 51, 103, 143, 203, 9],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "forfeitChallenge",
+      "discriminator": [97, 98, 179, 44, 74, 41, 98, 249],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "discriminator": [175, 175, 109, 31, 13, 152, 155, 237],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "state",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeChallenge",
+      "discriminator": [131, 92, 76, 227, 13, 71, 164, 243],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116, 114, 101, 97, 115, 117, 114, 121, 95, 97, 99, 99, 111,
+                  117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nativeTreasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116, 114, 101, 97, 115, 117, 114, 121, 95, 97, 99, 99, 111,
+                  117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "beneficiary",
+          "docs": [
+            "the forfeited pool, or a wallet for native challenges"
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "challengeType",
+          "type": {
+            "defined": {
+              "name": "ChallengeType"
+            }
+          }
+        },
+        {
+          "name": "challengeName",
+          "type": "string"
+        },
+        {
+          "name": "challengeDescription",
+          "type": "string"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "endTime",
+          "type": "i64"
+        },
+        {
+          "name": "moneyPerParticipant",
+          "type": "u64"
+        },
+        {
+          "name": "isPrivate",
+          "type": "bool"
+        },
+        {
+          "name": "privateGroup",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "rules",
+          "type": {
+            "defined": {
+              "name": "ChallengeRules"
+            }
+          }
+        },
+        {
+          "name": "isNative",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "joinChallenge",
+      "discriminator": [41, 104, 214, 73, 32, 168, 76, 79],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "userTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "merkleProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "inviteExpiry",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "stake",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "openDispute",
+      "discriminator": [137, 25, 99, 119, 23, 223, 161, 42],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "disputeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100, 105, 115, 112, 117, 116, 101, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "refundSponsor",
+      "discriminator": [137, 0, 158, 28, 172, 220, 165, 25],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "sponsorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sponsorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115, 112, 111, 110, 115, 111, 114, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "registerUser",
+      "discriminator": [2, 241, 150, 223, 99, 214, 116, 97],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userNameAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "userName",
+          "type": "string"
+        },
+        {
+          "name": "avatarUri",
+          "type": "string"
+        },
+        {
+          "name": "bioHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "renameUser",
+      "discriminator": [109, 81, 189, 34, 41, 143, 214, 255],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oldUserNameAccount",
+          "writable": true
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "newUserNameAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "userName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "requestJury",
+      "discriminator": [164, 150, 79, 133, 140, 104, 160, 62],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [231, 6, 202, 6, 96, 103, 12, 230],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "disputeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100, 105, 115, 112, 117, 116, 101, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "userAddress",
+          "type": "pubkey"
+        },
+        {
+          "name": "isUpheld",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "revokeSessionKey",
+      "discriminator": [81, 192, 32, 110, 104, 116, 144, 151],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sessionKeyAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 101, 115, 115, 105, 111, 110, 95, 107, 101, 121]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setArbiter",
+      "discriminator": [15, 205, 194, 180, 172, 213, 113, 211],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "state",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "arbiter",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setPlatformFee",
+      "discriminator": [19, 70, 111, 182, 156, 58, 208, 203],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "state",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "platformFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "feeRecipient",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "settleParticipants",
+      "discriminator": [174, 162, 127, 1, 107, 6, 75, 250],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sponsorChallenge",
+      "discriminator": [117, 88, 161, 197, 31, 175, 188, 237],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "sponsorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sponsorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115, 112, 111, 110, 115, 111, 114, 95, 97, 99, 99, 111, 117,
+                  110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitProof",
+      "discriminator": [54, 241, 46, 84, 4, 212, 46, 94],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "proofHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "proofUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "sweepToBeneficiary",
+      "discriminator": [174, 179, 89, 134, 22, 142, 91, 90],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "beneficiary",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sweepUnclaimed",
+      "discriminator": [64, 168, 221, 224, 42, 216, 138, 144],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true
+        },
+        {
+          "name": "feeRecipient",
+          "writable": true
+        },
+        {
+          "name": "feeRecipientTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeRecipient"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateChallenge",
+      "discriminator": [189, 212, 76, 181, 2, 202, 238, 16],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "challengeName",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "challengeDescription",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "membersToAdd",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "membersToRemove",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "endTime",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "moneyPerParticipant",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "challengeType",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "ChallengeType"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateChallengeStatus",
+      "discriminator": [193, 81, 19, 183, 19, 104, 28, 125],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "state",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112, 114, 111, 103, 114, 97, 109, 95, 111, 119, 110, 101, 114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "userAddress",
+          "type": "pubkey"
+        },
+        {
+          "name": "challengeVerification",
+          "type": {
+            "defined": {
+              "name": "ChallengeVerification"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateProfile",
+      "discriminator": [98, 67, 99, 206, 86, 115, 175, 1],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "avatarUri",
+          "type": "string"
+        },
+        {
+          "name": "bioHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "voteForVoteBasedChallenge",
+      "discriminator": [175, 201, 246, 165, 25, 22, 42, 199],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "voter"
+        },
+        {
+          "name": "sessionKeyAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 101, 115, 115, 105, 111, 110, 95, 107, 101, 121]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99, 104, 97, 108, 108, 101, 110, 103, 101, 95, 97, 99, 99,
+                  111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "challengeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userChallengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "userAddress"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voterChallengeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117, 115, 101, 114, 95, 99, 104, 97, 108, 108, 101, 110, 103,
+                  101, 95, 97, 99, 99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteTallyAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118, 111, 116, 101, 95, 116, 97, 108, 108, 121, 95, 97, 99,
+                  99, 111, 117, 110, 116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challengeAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "challengeId",
+          "type": "u64"
+        },
+        {
+          "name": "userAddress",
+          "type": "pubkey"
+        },
+        {
+          "name": "challengeVerification",
+          "type": {
+            "defined": {
+              "name": "ChallengeVerificationType"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ChallengeAccount",
+      "discriminator": [96, 128, 44, 165, 71, 172, 60, 12]
+    },
+    {
+      "name": "DisputeAccount",
+      "discriminator": [237, 70, 91, 63, 81, 74, 45, 43]
+    },
+    {
+      "name": "ProgramState",
+      "discriminator": [77, 209, 137, 229, 149, 67, 167, 230]
+    },
+    {
+      "name": "SessionKeyAccount",
+      "discriminator": [105, 86, 94, 211, 154, 53, 225, 208]
+    },
+    {
+      "name": "SponsorAccount",
+      "discriminator": [235, 50, 172, 241, 226, 187, 255, 196]
+    },
+    {
+      "name": "UserAccount",
+      "discriminator": [211, 33, 136, 16, 186, 110, 242, 127]
+    },
+    {
+      "name": "UserChallengeAccount",
+      "discriminator": [193, 251, 15, 111, 189, 96, 238, 190]
+    },
+    {
+      "name": "UserNameAccount",
+      "discriminator": [228, 58, 215, 197, 21, 148, 167, 116]
+    },
+    {
+      "name": "VoteTallyAccount",
+      "discriminator": [230, 56, 63, 190, 174, 72, 1, 191]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "UnAuthorized",
+      "msg": "User is not in the private group"
+    },
+    {
+      "code": 6001,
+      "name": "AlreadyJoined",
+      "msg": "User has already joined the challenge"
+    },
+    {
+      "code": 6002,
+      "name": "ChallengeStarted",
+      "msg": "Challenge has already started"
+    },
+    {
+      "code": 6003,
+      "name": "PrivateGroupEmpty",
+      "msg": "Private group is empty"
+    },
+    {
+      "code": 6004,
+      "name": "StartTimeInThePast",
+      "msg": "Start time is in the past"
+    },
+    {
+      "code": 6005,
+      "name": "EndTimeBeforeStartTime",
+      "msg": "End time is before start time"
+    },
+    {
+      "code": 6006,
+      "name": "UserDidNotParticipate",
+      "msg": "User did not participate in the challenge"
+    },
+    {
+      "code": 6007,
+      "name": "ChallengeNotEnded",
+      "msg": "Challenge is not ended"
+    },
+    {
+      "code": 6008,
+      "name": "UserHasNotCompletedTheChallenge",
+      "msg": "User has not completed the challenge"
+    },
+    {
+      "code": 6009,
+      "name": "AlreadyClaimed",
+      "msg": "User has already claimed the challenge"
+    },
+    {
+      "code": 6010,
+      "name": "ChallengeNotStarted",
+      "msg": "Challenge is not yet started"
+    },
+    {
+      "code": 6011,
+      "name": "ChallengeEnded",
+      "msg": "Challenge is ended"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidVerificationType",
+      "msg": "Invalid verification type"
+    },
+    {
+      "code": 6013,
+      "name": "ChallengeUnderVerification",
+      "msg": "Challenge is under verification"
+    },
+    {
+      "code": 6014,
+      "name": "ChallengeVerificationTimeEnded",
+      "msg": "Challenge verification time ended"
+    },
+    {
+      "code": 6015,
+      "name": "VoterIsVotingForHimself",
+      "msg": "Voter is voting for himself"
+    },
+    {
+      "code": 6016,
+      "name": "UnAuthorizedOwner",
+      "msg": "Unauthorized owner"
+    },
+    {
+      "code": 6017,
+      "name": "UserHasAlreadyVoted",
+      "msg": "User has already voted"
+    },
+    {
+      "code": 6018,
+      "name": "ProofSubmissionClosed",
+      "msg": "Proof submission is closed"
+    },
+    {
+      "code": 6019,
+      "name": "ProofUriTooLong",
+      "msg": "Proof uri is too long"
+    },
+    {
+      "code": 6020,
+      "name": "ProofNotSubmitted",
+      "msg": "User has not submitted the proof"
+    },
+    {
+      "code": 6021,
+      "name": "VotingNotStarted",
+      "msg": "Voting has not started yet"
+    },
+    {
+      "code": 6022,
+      "name": "DisputeWindowNotOpen",
+      "msg": "Dispute window is not open"
+    },
+    {
+      "code": 6023,
+      "name": "DisputeWindowClosed",
+      "msg": "Dispute window is closed"
+    },
+    {
+      "code": 6024,
+      "name": "AlreadyDisputed",
+      "msg": "User has already disputed the outcome"
+    },
+    {
+      "code": 6025,
+      "name": "NothingToDispute",
+      "msg": "User has completed the challenge, nothing to dispute"
+    },
+    {
+      "code": 6026,
+      "name": "DisputeNotOpen",
+      "msg": "Dispute is not open"
+    },
+    {
+      "code": 6027,
+      "name": "ChallengeUnderDispute",
+      "msg": "Challenge is under dispute"
+    },
+    {
+      "code": 6028,
+      "name": "UnAuthorizedArbiter",
+      "msg": "Unauthorized arbiter"
+    },
+    {
+      "code": 6029,
+      "name": "JurySizeTooLarge",
+      "msg": "Jury size is too large"
+    },
+    {
+      "code": 6030,
+      "name": "JuryAlreadyDrawn",
+      "msg": "Jury is already drawn"
+    },
+    {
+      "code": 6031,
+      "name": "JuryNotDrawn",
+      "msg": "Jury is not drawn yet"
+    },
+    {
+      "code": 6032,
+      "name": "NoJury",
+      "msg": "Challenge does not have a jury"
+    },
     {
-      name: "challengeAccount";
-      discriminator: [96, 128, 44, 165, 71, 172, 60, 12];
+      "code": 6033,
+      "name": "VoterIsNotAJuror",
+      "msg": "Voter is not a juror"
     },
     {
-      name: "programState";
-      discriminator: [77, 209, 137, 229, 149, 67, 167, 230];
+      "code": 6034,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Invalid remaining accounts"
     },
     {
-      name: "userAccount";
-      discriminator: [211, 33, 136, 16, 186, 110, 242, 127];
+      "code": 6035,
+      "name": "MissingVoteTally",
+      "msg": "Vote tally account is missing"
     },
     {
-      name: "userChallengeAccount";
-      discriminator: [193, 251, 15, 111, 189, 96, 238, 190];
+      "code": 6036,
+      "name": "ChallengeFull",
+      "msg": "Challenge is full"
     },
     {
-      name: "voteAccount";
-      discriminator: [203, 238, 154, 106, 200, 131, 0, 41];
-    }
-  ];
-  errors: [
+      "code": 6037,
+      "name": "InviteExpired",
+      "msg": "Invite is expired"
+    },
+    {
+      "code": 6038,
+      "name": "UnAuthorizedCreator",
+      "msg": "Unauthorized creator"
+    },
+    {
+      "code": 6039,
+      "name": "ChallengeHasParticipants",
+      "msg": "Challenge already has participants"
+    },
+    {
+      "code": 6040,
+      "name": "PrivateGroupFull",
+      "msg": "Private group is full"
+    },
+    {
+      "code": 6041,
+      "name": "EndTimeCanOnlyBeExtended",
+      "msg": "End time can only be extended"
+    },
+    {
+      "code": 6042,
+      "name": "FeeTooHigh",
+      "msg": "Fee is too high"
+    },
+    {
+      "code": 6043,
+      "name": "ChallengeNotFinalized",
+      "msg": "Challenge is not finalized"
+    },
+    {
+      "code": 6044,
+      "name": "ChallengeAlreadyFinalized",
+      "msg": "Challenge is already finalized"
+    },
+    {
+      "code": 6045,
+      "name": "NoFeesToClaim",
+      "msg": "No fees to claim"
+    },
+    {
+      "code": 6046,
+      "name": "NoBeneficiary",
+      "msg": "Challenge has no beneficiary"
+    },
+    {
+      "code": 6047,
+      "name": "NothingToSweep",
+      "msg": "Nothing to sweep"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidAmount",
+      "msg": "Amount must be more than zero"
+    },
+    {
+      "code": 6049,
+      "name": "SponsorshipNotRefundable",
+      "msg": "Sponsorship is only refunded when nobody completes the challenge"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidPayoutDistribution",
+      "msg": "Invalid payout distribution"
+    },
+    {
+      "code": 6051,
+      "name": "MissingTokenAccounts",
+      "msg": "Token accounts don't match the staking mode of the challenge"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidBeneficiary",
+      "msg": "Beneficiary is not a token account of the challenge mint"
+    },
     {
-      code: 6000;
-      name: "unAuthorized";
-      msg: "User is not in the private group";
+      "code": 6053,
+      "name": "UnsupportedMint",
+      "msg": "Mint has extensions that are not supported"
     },
     {
-      code: 6001;
-      name: "alreadyJoined";
-      msg: "User has already joined the challenge";
+      "code": 6054,
+      "name": "InvalidStake",
+      "msg": "Stake is out of the range of the challenge"
     },
     {
-      code: 6002;
-      name: "challengeStarted";
-      msg: "Challenge has already started";
+      "code": 6055,
+      "name": "InvalidForfeitRefund",
+      "msg": "Forfeit refund is too high"
     },
     {
-      code: 6003;
-      name: "privateGroupEmpty";
-      msg: "Private group is empty";
+      "code": 6056,
+      "name": "AlreadyForfeited",
+      "msg": "User has forfeited the challenge"
     },
     {
-      code: 6004;
-      name: "startTimeInThePast";
-      msg: "Start time is in the past";
+      "code": 6057,
+      "name": "ClaimDeadlineNotPassed",
+      "msg": "Claim deadline has not passed yet"
     },
     {
-      code: 6005;
-      name: "endTimeBeforeStartTime";
-      msg: "End time is before start time";
+      "code": 6058,
+      "name": "ChallengeNotSettled",
+      "msg": "Challenge still has funds to claim"
     },
     {
-      code: 6006;
-      name: "userDidNotParticipate";
-      msg: "User did not participate in the challenge";
+      "code": 6059,
+      "name": "SessionKeyExpired",
+      "msg": "Session key has expired"
     },
     {
-      code: 6007;
-      name: "challengeNotEnded";
-      msg: "Challenge is not ended";
+      "code": 6060,
+      "name": "InvalidSessionExpiry",
+      "msg": "Session expiry is out of range"
     },
     {
-      code: 6008;
-      name: "userHasNotCompletedTheChallenge";
-      msg: "User has not completed the challenge";
+      "code": 6061,
+      "name": "InvalidUserName",
+      "msg": "User name must be between 1 and 32 bytes"
     },
     {
-      code: 6009;
-      name: "alreadyClaimed";
-      msg: "User has already claimed the challenge";
+      "code": 6062,
+      "name": "AvatarUriTooLong",
+      "msg": "Avatar uri is too long"
     },
     {
-      code: 6010;
-      name: "challengeNotStarted";
-      msg: "Challenge is not yet started";
+      "code": 6063,
+      "name": "DisputeResolutionExpired",
+      "msg": "Dispute resolution deadline has passed"
     },
     {
-      code: 6011;
-      name: "challengeEnded";
-      msg: "Challenge is ended";
+      "code": 6064,
+      "name": "DisputeResolutionPending",
+      "msg": "Dispute can still be resolved by the arbiter"
     },
     {
-      code: 6012;
-      name: "invalidVerificationType";
-      msg: "Invalid verification type";
+      "code": 6065,
+      "name": "JuryNotRequested",
+      "msg": "Jury draw is not requested yet"
     },
     {
-      code: 6013;
-      name: "challengeUnderVerification";
-      msg: "Challenge is under verification";
+      "code": 6066,
+      "name": "JuryAlreadyRequested",
+      "msg": "Jury draw is already requested"
     },
     {
-      code: 6014;
-      name: "challengeVerificationTimeEnded";
-      msg: "Challenge verification time ended";
+      "code": 6067,
+      "name": "JurySlotNotReached",
+      "msg": "Slot of the jury draw is not reached yet"
     },
     {
-      code: 6015;
-      name: "voterIsVotingForHimself";
-      msg: "Voter is voting for himself";
+      "code": 6068,
+      "name": "JurySlotExpired",
+      "msg": "Slot hash of the jury draw has expired, request the draw again"
     },
     {
-      code: 6016;
-      name: "unAuthorizedOwner";
-      msg: "Unauthorized owner";
+      "code": 6069,
+      "name": "InvalidChallengeName",
+      "msg": "Challenge name must be between 1 and 32 bytes"
     },
     {
-      code: 6017;
-      name: "userHasAlreadyVoted";
-      msg: "User has already voted";
+      "code": 6070,
+      "name": "ChallengeDescriptionTooLong",
+      "msg": "Challenge description is too long"
+    },
+    {
+      "code": 6071,
+      "name": "ParticipantsNotSettled",
+      "msg": "Every participant has to be settled before the challenge is finalized"
+    },
+    {
+      "code": 6072,
+      "name": "MissingUserNameAccount",
+      "msg": "User name account is required when the name changes"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidClaimDuration",
+      "msg": "Claim duration is out of range"
     }
-  ];
-  types: [
+  ],
+  "types": [
     {
-      name: "challengeAccount";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "ChallengeAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challengeId",
+            "type": "u64"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "challengeInformation",
+            "type": {
+              "defined": {
+                "name": "ChallengeInformation"
+              }
+            }
+          },
+          {
+            "name": "rules",
+            "type": {
+              "defined": {
+                "name": "ChallengeRules"
+              }
+            }
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "totalParticipants",
+            "type": "u64"
+          },
+          {
+            "name": "totalVotes",
+            "type": "u64"
+          },
+          {
+            "name": "openDisputes",
+            "type": "u64"
+          },
+          {
+            "name": "isJuryDrawn",
+            "type": "bool"
+          },
+          {
+            "name": "jurors",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "jurySlot",
+            "type": "u64"
+          },
+          {
+            "name": "moneyPool",
+            "type": "u64"
+          },
+          {
+            "name": "moneyPerParticipant",
+            "type": "u64"
+          },
+          {
+            "name": "isNative",
+            "type": "bool"
+          },
+          {
+            "name": "sponsorPool",
+            "type": "u64"
+          },
+          {
+            "name": "openSponsorships",
+            "type": "u64"
+          },
+          {
+            "name": "treasuryAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "platformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "isFinalized",
+            "type": "bool"
+          },
+          {
+            "name": "settledParticipants",
+            "type": "u64"
+          },
+          {
+            "name": "rankedWinners",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "RankedWinner"
+                }
+              }
+            }
+          },
+          {
+            "name": "totalWinners",
+            "type": "u64"
+          },
+          {
+            "name": "pendingClaims",
+            "type": "u64"
+          },
+          {
+            "name": "closedParticipants",
+            "type": "u64"
+          },
           {
-            name: "challengeId";
-            type: "u64";
+            "name": "winnersStake",
+            "type": "u64"
           },
           {
-            name: "challengeInformation";
-            type: {
-              defined: {
-                name: "challengeInformation";
-              };
-            };
+            "name": "forfeitedPool",
+            "type": "u64"
           },
           {
-            name: "startTime";
-            type: "i64";
+            "name": "platformFee",
+            "type": "u64"
           },
           {
-            name: "endTime";
-            type: "i64";
+            "name": "creatorFee",
+            "type": "u64"
           },
           {
-            name: "totalParticipants";
-            type: "u64";
+            "name": "beneficiary",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            name: "totalVotes";
-            type: "u64";
+            "name": "beneficiaryAmount",
+            "type": "u64"
           },
           {
-            name: "moneyPool";
-            type: "u64";
+            "name": "isTreasuryClosed",
+            "type": "bool"
           },
           {
-            name: "moneyPerParticipant";
-            type: "u64";
+            "name": "treasuryBump",
+            "type": "u8"
           },
           {
-            name: "treasuryAccount";
-            type: "pubkey";
+            "name": "isPrivate",
+            "type": "bool"
           },
           {
-            name: "treasuryBump";
-            type: "u8";
+            "name": "privateGroup",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            name: "isPrivate";
-            type: "bool";
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeInformation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challengeType",
+            "type": {
+              "defined": {
+                "name": "ChallengeType"
+              }
+            }
           },
           {
-            name: "privateGroup";
-            type: {
-              vec: "pubkey";
-            };
+            "name": "challengeName",
+            "type": "string"
           },
           {
-            name: "bump";
-            type: "u8";
+            "name": "challengeDescription",
+            "type": "string"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "challengeInformation";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "ChallengeRules",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requiresProof",
+            "docs": [
+              "votes are only accepted for participants who submitted a proof"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "jurySize",
+            "docs": [
+              "number of jurors drawn from the participants to vote, 0 lets every participant vote"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allowlistRoot",
+            "docs": [
+              "merkle root of the addresses allowed to join a private challenge, next to the private group"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "creatorFeeBps",
+            "docs": [
+              "fee of the creator in basis points, taken from the forfeited stakes of the losers"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "payoutDistribution",
+            "docs": [
+              "shares of the forfeited pool in basis points for the top scorers of monitored challenges, empty splits it equally among the winners"
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "isProportional",
+            "docs": [
+              "participants of monitored challenges who fall short get back their deposit in proportion to their score"
+            ],
+            "type": "bool"
+          },
           {
-            name: "challengeType";
-            type: {
-              defined: {
-                name: "challengeType";
-              };
-            };
+            "name": "maxStake",
+            "docs": [
+              "participants can stake anything between money_per_participant and this amount, 0 keeps the stake fixed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "forfeitRefundBps",
+            "docs": [
+              "part of the deposit in basis points given back to participants who quit during the challenge"
+            ],
+            "type": "u16"
           },
           {
-            name: "challengeName";
-            type: "string";
+            "name": "inviteOnly",
+            "docs": [
+              "private challenges can only be joined with an invite signed by the creator, without a private group or an allowlist"
+            ],
+            "type": "bool"
           },
           {
-            name: "challengeDescription";
-            type: "string";
+            "name": "claimDuration",
+            "docs": [
+              "seconds given to the winners to claim once the disputes are over, the rest is swept afterwards"
+            ],
+            "type": "i64"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "challengeType";
-      type: {
-        kind: "enum";
-        variants: [
+      "name": "ChallengeType",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            name: "googleFit";
-            fields: [
+            "name": "GoogleFit",
+            "fields": [
               {
-                name: "steps";
-                type: "u64";
+                "name": "steps",
+                "type": "u64"
               }
-            ];
+            ]
           },
           {
-            name: "github";
-            fields: [
+            "name": "Github",
+            "fields": [
               {
-                name: "commits";
-                type: "u64";
+                "name": "commits",
+                "type": "u64"
               }
-            ];
+            ]
           },
           {
-            name: "voteBased";
+            "name": "VoteBased"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "challengeVerification";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "ChallengeVerification",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            name: "challengeType";
-            type: {
-              defined: {
-                name: "challengeVerificationType";
-              };
-            };
+            "name": "challengeType",
+            "type": {
+              "defined": {
+                "name": "ChallengeVerificationType"
+              }
+            }
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "challengeVerificationType";
-      type: {
-        kind: "enum";
-        variants: [
+      "name": "ChallengeVerificationType",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            name: "monitored";
-            fields: [
+            "name": "Monitored",
+            "fields": [
               {
-                name: "score";
-                type: "u64";
+                "name": "score",
+                "type": "u64"
               },
               {
-                name: "isCompleted";
-                type: "bool";
+                "name": "isCompleted",
+                "type": "bool"
               }
-            ];
+            ]
           },
           {
-            name: "voteBased";
-            fields: [
+            "name": "VoteBased",
+            "fields": [
               {
-                name: "isCompleted";
-                type: "bool";
+                "name": "isCompleted",
+                "type": "bool"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DisputeAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challengeAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "userAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DisputeStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Upheld"
+          },
+          {
+            "name": "Rejected"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "arbiter",
+            "type": "pubkey"
+          },
+          {
+            "name": "platformFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RankedWinner",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "participantIndex",
+            "type": "u64"
+          },
+          {
+            "name": "score",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionKeyAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "SessionScope"
               }
-            ];
+            }
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "programState";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "SessionScope",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Voting"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SponsorAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challengeAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "sponsorAddress",
+            "type": "pubkey"
+          },
           {
-            name: "owner";
-            type: "pubkey";
+            "name": "amount",
+            "type": "u64"
           },
           {
-            name: "bump";
-            type: "u8";
+            "name": "bump",
+            "type": "u8"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "userAccount";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "UserAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            name: "userName";
-            type: "string";
+            "name": "userName",
+            "type": "string"
           },
           {
-            name: "userAddress";
-            type: "pubkey";
+            "name": "avatarUri",
+            "type": "string"
           },
           {
-            name: "totalParticipations";
-            type: "u64";
+            "name": "bioHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "userAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalParticipations",
+            "type": "u64"
+          },
+          {
+            "name": "totalMoneyDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "totalMoneyWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "wins",
+            "type": "u64"
+          },
+          {
+            "name": "losses",
+            "type": "u64"
+          },
+          {
+            "name": "currentStreak",
+            "type": "u64"
           },
           {
-            name: "totalMoneyDeposited";
-            type: "u64";
+            "name": "bestStreak",
+            "type": "u64"
           },
           {
-            name: "totalMoneyWithdrawn";
-            type: "u64";
+            "name": "reputation",
+            "type": "u64"
           },
           {
-            name: "bump";
-            type: "u8";
+            "name": "bump",
+            "type": "u8"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "userChallengeAccount";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "UserChallengeAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challengeAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "userAddress",
+            "type": "pubkey"
+          },
+          {
+            "name": "isJoined",
+            "type": "bool"
+          },
+          {
+            "name": "participantIndex",
+            "type": "u64"
+          },
+          {
+            "name": "moneyDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": "u64"
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          },
           {
-            name: "challengeAddress";
-            type: "pubkey";
+            "name": "hasForfeited",
+            "type": "bool"
           },
           {
-            name: "description";
-            type: "string";
+            "name": "rentPayer",
+            "type": "pubkey"
           },
           {
-            name: "userAddress";
-            type: "pubkey";
+            "name": "isChallengeCompleted",
+            "type": "bool"
           },
           {
-            name: "isJoined";
-            type: "bool";
+            "name": "bump",
+            "type": "u8"
           },
           {
-            name: "moneyDeposited";
-            type: "u64";
+            "name": "score",
+            "type": "u64"
           },
           {
-            name: "isChallengeCompleted";
-            type: "bool";
+            "name": "isProofSubmitted",
+            "type": "bool"
           },
           {
-            name: "bump";
-            type: "u8";
+            "name": "proofHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "proofUri",
+            "type": "string"
+          },
+          {
+            "name": "disputeStatus",
+            "type": {
+              "defined": {
+                "name": "DisputeStatus"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UserNameAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userAddress",
+            "type": "pubkey"
           },
           {
-            name: "score";
-            type: "u64";
+            "name": "rentPayer",
+            "type": "pubkey"
           },
           {
-            name: "voteInPositive";
-            type: "u64";
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userAddress",
+            "type": "pubkey"
           },
           {
-            name: "voteInNegative";
-            type: "u64";
+            "name": "isCompleted",
+            "type": "bool"
           }
-        ];
-      };
+        ]
+      }
     },
     {
-      name: "voteAccount";
-      type: {
-        kind: "struct";
-        fields: [
+      "name": "VoteTallyAccount",
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            name: "challengeAddress";
-            type: "pubkey";
+            "name": "challengeAddress",
+            "type": "pubkey"
           },
           {
-            name: "userAddress";
-            type: "pubkey";
+            "name": "voted",
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u64",
+                    2
+                  ]
+                },
+                128
+              ]
+            }
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u64",
+                    2
+                  ]
+                },
+                128
+              ]
+            }
+          },
+          {
+            "name": "voteInPositive",
+            "type": {
+              "array": [
+                "u16",
+                128
+              ]
+            }
           },
           {
-            name: "isVoted";
-            type: "bool";
+            "name": "voteInNegative",
+            "type": {
+              "array": [
+                "u16",
+                128
+              ]
+            }
           },
           {
-            name: "isCompleted";
-            type: "bool";
+            "name": "voteRevisions",
+            "type": {
+              "array": [
+                "u16",
+                128
+              ]
+            }
           },
           {
-            name: "bump";
-            type: "u8";
+            "name": "bump",
+            "type": "u8"
           },
           {
-            name: "voterAddress";
-            type: "pubkey";
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
           }
-        ];
-      };
+        ]
+      }
     }
-  ];
+  ]
 };
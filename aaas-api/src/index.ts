@@ -134,6 +134,13 @@ function getChallengeAccountPDA(challengeId: number) {
   );
 }
 
+function getVoteTallyAccountPDA(challengeAccountPDA: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vote_tally_account"), challengeAccountPDA.toBuffer()],
+    program.programId
+  );
+}
+
 // 10 Lamports of JKCOIN per challenge vote
 async function transferJKCOIN(to: PublicKey, amount: number) {
   try {
@@ -190,37 +197,31 @@ app.post("/vote-claim", async (req: Request, res: Response) => {
           participant.account.challengeAddress.toString() ==
           challengeAccountPDA[0].toString()
       );
-      // now check if there exists voteAccount corresponding to the participant
-      const correspondingVoteAccount = (
-        await program.account.voteAccount.all()
-      ).filter((voteAccount) => {
-        return (
-          participants.some(
-            (participant) =>
-              participant.account.userAddress.toString() ==
-              voteAccount.account.userAddress.toString()
-          ) &&
-          challengeAccountPDA[0].toString() ==
-            voteAccount.account.challengeAddress.toString() &&
-          publicKey == voteAccount.account.voterAddress.toString()
-        );
-      });
-
-      console.log("correspondingVoteAccount", correspondingVoteAccount);
-      console.log("participants", participants);
-
-      // check if the voter has voted everyone except himself and other case that he has not participated in the challenge
-      if (
-        correspondingVoteAccount.length == participants.length - 1 &&
-        participants.length != 1
-      ) {
-        // now check if the voter has voted everyone except himself
-        const voter = participants.find(
-          (participant) => participant.account.userAddress == keypair.publicKey
-        );
+      // only participants vote, the votes are kept in the vote tally of the
+      // challenge where voters and participants are addressed by their index
+      const voter = participants.find(
+        (participant) => participant.account.userAddress.toString() == publicKey
+      );
+      const voteTally = await program.account.voteTallyAccount.fetch(
+        getVoteTallyAccountPDA(challengeAccountPDA[0])[0]
+      );
+
+      // check if the voter has voted everyone except himself
+      if (voter && participants.length != 1) {
+        const voterIndex = voter.account.participantIndex.toNumber();
+        const hasVotedEveryone = participants.every((participant) => {
+          const participantIndex =
+            participant.account.participantIndex.toNumber();
+          const votedWord =
+            voteTally.voted[voterIndex][Math.floor(participantIndex / 64)];
+          return (
+            participantIndex == voterIndex ||
+            votedWord.testn(participantIndex % 64)
+          );
+        });
         console.log("voter", voter);
-        if (voter) {
-          console.log("voter", voter);
+        console.log("hasVotedEveryone", hasVotedEveryone);
+        if (hasVotedEveryone) {
           // valid case
           await transferJKCOIN(voter.account.userAddress, 10);
           console.log("Vote claimed successfully");
@@ -229,17 +230,6 @@ app.post("/vote-claim", async (req: Request, res: Response) => {
           });
           return;
         }
-      } else if (
-        correspondingVoteAccount.length == participants.length &&
-        participants.length != 0
-      ) {
-        // valid case
-        console.log("voting");
-        await transferJKCOIN(new PublicKey(publicKey), 10);
-        res.json({
-          message: "Vote claimed successfully",
-        });
-        return;
       }
     }
     console.log("challengeAccount", challengeAccount);
@@ -100,15 +100,12 @@ export default function ChallengeDetailsScreen() {
       // Check if user has joined this challenge
       if (userPublickey) {
         try {
-          const userChallengeAccount =
-            await contractService.getUserChallengeAccountPDA(
-              userPublickey,
-              challengeAccountPDA
-            );
-
+          // The status comes with the votes of vote verified challenges
           const userChallengeData =
-            await program.account.userChallengeAccount.fetch(
-              userChallengeAccount
+            await contractService.getUserChallengeStatus(
+              program,
+              parseInt(id as string, 10),
+              userPublickey
             );
           setUserChallengeStatus(userChallengeData);
         } catch (error) {
@@ -154,7 +151,7 @@ export default function ChallengeDetailsScreen() {
       // Call the joinChallenge function from wallet context
       const signature = await joinChallenge(
         parseInt(challenge.id, 10),
-        "User", // Default username
+        userPublickey.toBase58().slice(0, 8), // Default username for new users
         "I'm excited to participate!" // Default description
       );
 
@@ -286,11 +283,11 @@ export default function ChallengeDetailsScreen() {
     );
     if (now < thirtyMinutesAfterEnd) return false;
 
-    // For vote-based challenges, check if positive votes are greater than negative votes
+    // For vote-based challenges, check if positive votes >= negative votes
     if (challenge.challenge_type === "Votebased") {
       return (
         userChallengeStatus.isChallengeCompleted ||
-        userChallengeStatus.voteInPositive > userChallengeStatus.voteInNegative
+        userChallengeStatus.voteInPositive >= userChallengeStatus.voteInNegative
       );
     }
 
@@ -303,11 +300,11 @@ export default function ChallengeDetailsScreen() {
     if (!challenge || !userPublickey || !userChallengeStatus) return false;
     if (status !== ChallengeStatus.COMPLETED) return false;
 
-    // For vote-based challenges, check if negative votes are greater than or equal to positive votes
+    // For vote-based challenges, check if negative votes exceed positive votes
     if (challenge.challenge_type === "Votebased") {
       return (
         !userChallengeStatus.isChallengeCompleted &&
-        userChallengeStatus.voteInNegative >= userChallengeStatus.voteInPositive
+        userChallengeStatus.voteInNegative > userChallengeStatus.voteInPositive
       );
     }
 
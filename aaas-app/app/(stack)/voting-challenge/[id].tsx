@@ -133,32 +133,35 @@ export default function VotingChallengeScreen() {
           },
         ]);
 
+      // The votes of the challenge are kept in its vote tally
+      const voteTally = await program.account.voteTallyAccount.fetch(
+        await contractService.getVoteTallyAccountPDA(challengeAccountPDA)
+      );
+      // Only participants vote, they are addressed by their participant index
+      const voter = userChallengeAccounts.find((account) =>
+        userPublickey?.equals(account.account.userAddress)
+      );
+
       // Format participants data
       const participants: Participant[] = await Promise.all(
         userChallengeAccounts.map(async (account) => {
           const userChallengeData = account.account;
+          const participantIndex =
+            userChallengeData.participantIndex.toNumber();
 
           // For each participant, check if the current user has already voted
-          const voteAccountAddress = await contractService.getVoteAccountPDA(
-            challengeAccountPDA,
-            new PublicKey(userChallengeData.userAddress),
-            userPublickey
-          );
-
-          let hasAlreadyVoted = false;
-          try {
-            const voteAccount = await program.account.voteAccount.fetch(
-              voteAccountAddress
-            );
-            if (voteAccount.isVoted) {
-              setHasVoted((prev) => ({
-                ...prev,
-                [userChallengeData.userAddress.toString()]: true,
-              }));
-              hasAlreadyVoted = true;
-            }
-          } catch (error) {
-            // Vote account doesn't exist yet, so user hasn't voted
+          if (
+            voter &&
+            contractService.hasVotedFor(
+              voteTally,
+              voter.account.participantIndex.toNumber(),
+              participantIndex
+            )
+          ) {
+            setHasVoted((prev) => ({
+              ...prev,
+              [userChallengeData.userAddress.toString()]: true,
+            }));
           }
 
           return {
@@ -170,8 +173,8 @@ export default function VotingChallengeScreen() {
             user_name: userChallengeData.description.split(" ")[0], // Using first word of description as username
             description: userChallengeData.description,
             submission_description: userChallengeData.description,
-            vote_in_positive: userChallengeData.voteInPositive.toNumber(),
-            vote_in_negative: userChallengeData.voteInNegative.toNumber(),
+            vote_in_positive: voteTally.voteInPositive[participantIndex],
+            vote_in_negative: voteTally.voteInNegative[participantIndex],
             user_challenge_account_address: account.publicKey.toString(),
           };
         })
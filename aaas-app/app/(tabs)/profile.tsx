@@ -101,19 +101,13 @@ export default function ProfileScreen() {
         try {
           // For each challenge, check if the user has joined
           const challengeId = parseInt(challenge.id);
-          const challengeAccount = await contractService.getChallengeAccountPDA(
-            challengeId
-          );
-          const userChallengeAccount =
-            await contractService.getUserChallengeAccountPDA(
-              userPublickey,
-              challengeAccount
-            );
 
           // Try to fetch the user challenge account - if it exists, the user has joined
           const userChallengeData =
-            await program.account.userChallengeAccount.fetch(
-              userChallengeAccount
+            await contractService.getUserChallengeStatus(
+              program,
+              challengeId,
+              userPublickey
             );
 
           if (userChallengeData) {
@@ -167,12 +161,8 @@ export default function ProfileScreen() {
               }),
               ...(challenge.challenge_type === "Votebased" && {
                 votes: {
-                  positive: userChallengeData.voteInPositive
-                    ? userChallengeData.voteInPositive.toNumber()
-                    : 0,
-                  negative: userChallengeData.voteInNegative
-                    ? userChallengeData.voteInNegative.toNumber()
-                    : 0,
+                  positive: userChallengeData.voteInPositive,
+                  negative: userChallengeData.voteInNegative,
                 },
               }),
             });
@@ -208,21 +198,35 @@ export default function ProfileScreen() {
         return;
       }
       console.log("userPublickey", userPublickey);
-      const voteAccounts = (await program.account.voteAccount.all()).filter(
-        (voteAccount) => {
-          return voteAccount.account.voterAddress.equals(userPublickey);
+      // Only participants vote, so only the joined challenges are checked
+      const voteTallies = await program.account.voteTallyAccount.all();
+      console.log("voteTallies", voteTallies);
+
+      for (const voteTally of voteTallies) {
+        const userChallengeData =
+          await program.account.userChallengeAccount.fetchNullable(
+            await contractService.getUserChallengeAccountPDA(
+              userPublickey,
+              voteTally.account.challengeAddress
+            )
+          );
+        if (
+          !userChallengeData ||
+          !contractService.hasVoted(
+            voteTally.account,
+            userChallengeData.participantIndex.toNumber()
+          )
+        ) {
+          continue;
         }
-      );
-      console.log("voteAccounts", voteAccounts);
 
-      for (const voteAccount of voteAccounts) {
         const challengeData = await program.account.challengeAccount.fetch(
-          voteAccount.account.challengeAddress.toString()
+          voteTally.account.challengeAddress.toString()
         );
         console.log("challengeData", challengeData);
         setVotedChallenges((prev) => {
           const newMap = new Map(prev);
-          newMap.set(voteAccount.account.challengeAddress.toString(), {
+          newMap.set(voteTally.account.challengeAddress.toString(), {
             id: challengeData.challengeId.toString(),
             title: challengeData.challengeInformation.challengeName,
             start_time: challengeData.startTime * 1000,
@@ -10,6 +10,7 @@ export function useAaasContract() {
     isConnected,
     userPublickey,
     signAndSendTransaction,
+    signAndSendAllTransaction,
     anchorWallet,
     program,
   } = useWallet();
@@ -39,7 +40,8 @@ export function useAaasContract() {
     }
 
     try {
-      const transaction = await contractService.joinChallenge(
+      // Users who are not registered yet are registered with the user name
+      const transactions = await contractService.joinChallenge(
         program,
         challengeId,
         userName,
@@ -48,7 +50,7 @@ export function useAaasContract() {
         DEFAULT_MINT
       );
 
-      return await signAndSendTransaction(transaction);
+      return await signAndSendAllTransaction(transactions);
     } catch (error) {
       // console.error("Error joining challenge:", error);
       throw error;
@@ -89,14 +91,25 @@ export function useAaasContract() {
     }
 
     try {
-      const transaction = await contractService.claimChallenge(
+      // The participants have to be settled before the challenge is finalized,
+      // large challenges are settled over several transactions
+      const settleTransactions = await contractService.settleParticipants(
+        program,
+        challengeId,
+        userPublickey
+      );
+      for (const transaction of settleTransactions) {
+        await signAndSendTransaction(transaction);
+      }
+
+      const transactions = await contractService.claimChallenge(
         program,
         challengeId,
         userPublickey,
         DEFAULT_MINT
       );
 
-      return await signAndSendTransaction(transaction);
+      return await signAndSendAllTransaction(transactions);
     } catch (error) {
       console.error("Error claiming challenge:", error);
       throw error;
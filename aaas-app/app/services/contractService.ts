@@ -4,11 +4,23 @@ import {
   Transaction,
   TransactionInstruction,
 } from "@solana/web3.js";
-import { Program, Wallet, BN, AnchorProvider } from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  Program,
+  Wallet,
+  BN,
+  AnchorProvider,
+  IdlAccounts,
+} from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import * as Crypto from "expo-crypto";
 import idl from "../idl.json";
 import { AaasContract } from "../aaas-contract";
 
+type VoteTally = IdlAccounts<AaasContract>["voteTallyAccount"];
+
 // Challenge Type Enum
 export enum ChallengeType {
   GOOGLE_FIT = "GoogleFit",
@@ -17,9 +29,25 @@ export enum ChallengeType {
 }
 
 // Contract address
-const PROGRAM_ID = new PublicKey(
-  "2qZ14Js1pzXRRDC2CWLYeRCNx7Lge6sEzFmoRSsZz1Cj"
-);
+const PROGRAM_ID = new PublicKey(idl.address);
+
+// Participants settled per transaction before the challenge can be finalized
+const SETTLE_BATCH_SIZE = 8;
+
+// Rules of the challenges created from the app
+export const DEFAULT_CHALLENGE_RULES = {
+  requiresProof: false,
+  jurySize: 0,
+  allowlistRoot: null,
+  creatorFeeBps: 0,
+  payoutDistribution: [],
+  isProportional: false,
+  maxStake: new BN(0),
+  forfeitRefundBps: 0,
+  inviteOnly: false,
+  // a week to claim once the disputes are over
+  claimDuration: new BN(7 * 24 * 60 * 60),
+};
 
 // Convert string to PublicKey
 export const stringToPublicKey = (address: string): PublicKey => {
@@ -79,24 +107,47 @@ export const getUserChallengeAccountPDA = async (
   return pda;
 };
 
-// Get PDA for vote account
-export const getVoteAccountPDA = async (
-  challengeAccountPDA: PublicKey,
-  userPubkey: PublicKey,
-  signerPubkey: PublicKey
+// Get PDA for user name account, names are unique regardless of their case
+export const getUserNameAccountPDA = async (userName: string) => {
+  const userNameHash = await Crypto.digestStringAsync(
+    Crypto.CryptoDigestAlgorithm.SHA256,
+    userName.toLowerCase()
+  );
+  const [pda] = await PublicKey.findProgramAddressSync(
+    [Buffer.from("user_name"), Buffer.from(userNameHash, "hex")],
+    PROGRAM_ID
+  );
+  return pda;
+};
+
+// Get PDA for the vote tally of a vote verified challenge
+export const getVoteTallyAccountPDA = async (
+  challengeAccountPDA: PublicKey
 ) => {
   const [pda] = await PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("vote_account"),
-      challengeAccountPDA.toBuffer(),
-      signerPubkey.toBuffer(),
-      userPubkey.toBuffer(),
-    ],
+    [Buffer.from("vote_tally_account"), challengeAccountPDA.toBuffer()],
     PROGRAM_ID
   );
   return pda;
 };
 
+// Check in the vote tally if a voter has voted for a participant,
+// both are addressed by their participant index
+export const hasVotedFor = (
+  voteTally: VoteTally,
+  voterIndex: number,
+  participantIndex: number
+) => {
+  return voteTally.voted[voterIndex][Math.floor(participantIndex / 64)].testn(
+    participantIndex % 64
+  );
+};
+
+// Check in the vote tally if a voter has voted for anyone
+export const hasVoted = (voteTally: VoteTally, voterIndex: number) => {
+  return voteTally.voted[voterIndex].some((word: BN) => !word.isZero());
+};
+
 // Get PDA for treasury account
 export const getTreasuryAccountPDA = async (challengeId: number) => {
   const [pda] = await PublicKey.findProgramAddressSync(
@@ -124,7 +175,33 @@ export const getAllChallenges = async (program: Program<AaasContract>) => {
   }
 };
 
-// Join a challenge
+// Register a user, a registered user account is needed to join a challenge
+export const registerUser = async (
+  program: Program<AaasContract>,
+  userName: string,
+  userPublicKey: PublicKey
+) => {
+  try {
+    const userNameAccount = await getUserNameAccountPDA(userName);
+    // Register user transaction
+    const tx = await program.methods
+      .registerUser(userName, "", Array(32).fill(0))
+      .accounts({
+        signer: userPublicKey,
+        payer: userPublicKey,
+        userNameAccount: userNameAccount,
+      })
+      .instruction();
+
+    return tx;
+  } catch (error) {
+    // console.error("Error registering user:", error);
+    throw error;
+  }
+};
+
+// Join a public challenge at the fixed stake, users who are not registered yet
+// are registered with the user name in the same transaction
 export const joinChallenge = async (
   program: Program<AaasContract>,
   challengeId: number,
@@ -134,19 +211,28 @@ export const joinChallenge = async (
   mint: PublicKey
 ) => {
   try {
+    const instructions: TransactionInstruction[] = [];
+    const userAccount = await getUserAccountPDA(userPublicKey);
+    if (!(await program.account.userAccount.fetchNullable(userAccount))) {
+      instructions.push(await registerUser(program, userName, userPublicKey));
+    }
+
     const treasuryAccount = await getTreasuryAccountPDA(challengeId);
     // Join challenge transaction
     const tx = await program.methods
-      .joinChallenge(new BN(challengeId), userName, description)
+      .joinChallenge(new BN(challengeId), description, [], null, null)
       .accounts({
         signer: userPublicKey,
+        payer: userPublicKey,
         mint: mint,
         treasuryAccount: treasuryAccount,
+        userTokenAccount: getAssociatedTokenAddressSync(mint, userPublicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
+    instructions.push(tx);
 
-    return tx;
+    return instructions;
   } catch (error) {
     // console.error("Error joining challenge:", error);
     throw error;
@@ -169,7 +255,7 @@ export const voteForChallenge = async (
       },
     };
 
-    // Vote transaction
+    // Vote transaction, the voter signs for themselves
     const tx = await program.methods
       .voteForVoteBasedChallenge(
         new BN(challengeId),
@@ -178,6 +264,8 @@ export const voteForChallenge = async (
       )
       .accounts({
         signer: voterPublicKey,
+        voter: voterPublicKey,
+        sessionKeyAccount: null,
       })
       .instruction();
 
@@ -188,7 +276,79 @@ export const voteForChallenge = async (
   }
 };
 
-// Claim a challenge reward
+// Settle the participants not settled yet, in the order they joined,
+// a challenge can only be finalized once all of them are settled
+export const settleParticipants = async (
+  program: Program<AaasContract>,
+  challengeId: number,
+  userPublicKey: PublicKey
+) => {
+  try {
+    const challengeAccount = await getChallengeAccountPDA(challengeId);
+    const challengeData = await program.account.challengeAccount.fetch(
+      challengeAccount
+    );
+    const isVoteVerified = !(
+      "googleFit" in challengeData.challengeInformation.challengeType
+    );
+
+    const participants = (
+      await program.account.userChallengeAccount.all([
+        {
+          memcmp: {
+            offset: 8, // after discriminator
+            bytes: challengeAccount.toBase58(),
+          },
+        },
+      ])
+    )
+      .filter(
+        (participant) =>
+          participant.account.isJoined &&
+          participant.account.participantIndex.gte(
+            challengeData.settledParticipants
+          )
+      )
+      .sort((a, b) =>
+        a.account.participantIndex.cmp(b.account.participantIndex)
+      );
+
+    // Settle transactions
+    const instructions: TransactionInstruction[] = [];
+    for (let i = 0; i < participants.length; i += SETTLE_BATCH_SIZE) {
+      const remainingAccounts = [];
+      for (const participant of participants.slice(i, i + SETTLE_BATCH_SIZE)) {
+        remainingAccounts.push(
+          { pubkey: participant.publicKey, isSigner: false, isWritable: true },
+          {
+            pubkey: await getUserAccountPDA(participant.account.userAddress),
+            isSigner: false,
+            isWritable: true,
+          }
+        );
+      }
+
+      const tx = await program.methods
+        .settleParticipants(new BN(challengeId))
+        .accounts({
+          signer: userPublicKey,
+          voteTallyAccount: isVoteVerified
+            ? await getVoteTallyAccountPDA(challengeAccount)
+            : null,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+      instructions.push(tx);
+    }
+
+    return instructions;
+  } catch (error) {
+    console.error("Error settling participants:", error);
+    throw error;
+  }
+};
+
+// Claim a challenge reward, the challenge is finalized first if needed
 export const claimChallenge = async (
   program: Program<AaasContract>,
   challengeId: number,
@@ -196,26 +356,45 @@ export const claimChallenge = async (
   mint: PublicKey
 ) => {
   try {
+    const instructions: TransactionInstruction[] = [];
+    const challengeAccount = await getChallengeAccountPDA(challengeId);
+    const challengeData = await program.account.challengeAccount.fetch(
+      challengeAccount
+    );
+    if (!challengeData.isFinalized) {
+      instructions.push(
+        await program.methods
+          .finalizeChallenge(new BN(challengeId))
+          .accounts({
+            signer: userPublicKey,
+          })
+          .instruction()
+      );
+    }
+
     const treasuryAccount = await getTreasuryAccountPDA(challengeId);
 
     // Claim challenge transaction
     const tx = await program.methods
       .claimChallenge(new BN(challengeId))
       .accounts({
+        signer: userPublicKey,
         mint: mint,
         treasuryAccount: treasuryAccount,
+        userTokenAccount: getAssociatedTokenAddressSync(mint, userPublicKey),
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
+    instructions.push(tx);
 
-    return tx;
+    return instructions;
   } catch (error) {
     console.error("Error claiming challenge:", error);
     throw error;
   }
 };
 
-// Get user challenge status
+// Get user challenge status, with the votes of vote verified challenges
 export const getUserChallengeStatus = async (
   program: Program<AaasContract>,
   challengeId: number,
@@ -232,7 +411,21 @@ export const getUserChallengeStatus = async (
     const userChallengeData = await program.account.userChallengeAccount.fetch(
       userChallengeAccount
     );
-    return userChallengeData;
+
+    // The votes are kept in the vote tally of the challenge
+    const voteTally = await program.account.voteTallyAccount.fetchNullable(
+      await getVoteTallyAccountPDA(challengeAccount)
+    );
+    const participantIndex = userChallengeData.participantIndex.toNumber();
+    return {
+      ...userChallengeData,
+      voteInPositive: voteTally
+        ? voteTally.voteInPositive[participantIndex]
+        : 0,
+      voteInNegative: voteTally
+        ? voteTally.voteInNegative[participantIndex]
+        : 0,
+    };
   } catch (error) {
     // console.error("Error getting user challenge status:", error);
     throw error;
@@ -361,6 +554,13 @@ export const initializeChallenge = async (
       challengeType: challengeTypeObj,
     };
 
+    // The votes of vote verified challenges are kept in a vote tally
+    const challengeAccount = await getChallengeAccountPDA(challengeId);
+    const voteTallyAccount =
+      challengeType === ChallengeType.GOOGLE_FIT
+        ? null
+        : await getVoteTallyAccountPDA(challengeAccount);
+
     // Follow the same pattern as other functions (e.g., joinChallenge)
     // @ts-ignore - Type issues are expected because of the IDL structure
     const tx = await program.methods
@@ -373,11 +573,17 @@ export const initializeChallenge = async (
         new BN(endTimeUnix),
         moneyPerParticipantLamports,
         isPrivate,
-        privateGroupPubkeys
+        privateGroupPubkeys,
+        DEFAULT_CHALLENGE_RULES,
+        false // paid in the token of the mint
       )
       .accounts({
         signer: userPublicKey,
         mint,
+        treasuryAccount: await getTreasuryAccountPDA(challengeId),
+        nativeTreasuryAccount: null,
+        voteTallyAccount,
+        beneficiary: null,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .instruction();
@@ -0,0 +1,75 @@
+// time given to the verification service / voters after the challenge ends
+#[cfg(not(feature = "short-durations"))]
+pub const VERIFICATION_DURATION: i64 = 30 * 60;
+
+// the end to end tests are built with windows of a few seconds, so they can wait for them to pass
+#[cfg(feature = "short-durations")]
+pub const VERIFICATION_DURATION: i64 = 6;
+
+// time given to participants of vote verified challenges to submit their proof, before voting starts
+#[cfg(not(feature = "short-durations"))]
+pub const PROOF_SUBMISSION_DURATION: i64 = 15 * 60;
+
+#[cfg(feature = "short-durations")]
+pub const PROOF_SUBMISSION_DURATION: i64 = 4;
+
+// time given to participants to dispute their outcome once the verification is over
+#[cfg(not(feature = "short-durations"))]
+pub const DISPUTE_DURATION: i64 = 60 * 60;
+
+#[cfg(feature = "short-durations")]
+pub const DISPUTE_DURATION: i64 = 6;
+
+// time given to the arbiter to resolve the disputes once the dispute window is over,
+// disputes still open afterwards are rejected
+pub const DISPUTE_RESOLUTION_DURATION: i64 = DISPUTE_DURATION;
+
+// bounds of the time the creator gives the winners to claim once the disputes are over,
+// the rest is swept afterwards
+#[cfg(not(feature = "short-durations"))]
+pub const MIN_CLAIM_DURATION: i64 = 7 * 24 * 60 * 60;
+
+#[cfg(feature = "short-durations")]
+pub const MIN_CLAIM_DURATION: i64 = 4;
+
+pub const MAX_CLAIM_DURATION: i64 = 365 * 24 * 60 * 60;
+
+// lamports a participant has to lock to open a dispute
+pub const DISPUTE_BOND: u64 = 100_000_000;
+
+// vote verified challenges are limited by the size of the vote tally, whose voter x participant
+// bitmaps have to fit in an account the program can create
+pub const MAX_VOTE_PARTICIPANTS: usize = 128;
+
+pub const VOTE_BITMAP_WORDS: usize = MAX_VOTE_PARTICIPANTS.div_ceil(64);
+
+pub const MAX_JURY_SIZE: u8 = 9;
+
+// the jury is drawn from the hash of a slot this far after the request, unknown when it is requested
+pub const JURY_SLOT_DELAY: u64 = 8;
+
+pub const MAX_CHALLENGE_NAME_LEN: usize = 32;
+
+pub const MAX_CHALLENGE_DESCRIPTION_LEN: usize = 256;
+
+// members of the private group of a challenge
+pub const MAX_PRIVATE_GROUP_LEN: usize = 10;
+
+pub const MAX_PROOF_URI_LEN: usize = 200;
+
+pub const MAX_USER_NAME_LEN: usize = 32;
+
+pub const MAX_AVATAR_URI_LEN: usize = 200;
+
+// fees are expressed in basis points of the forfeited pool
+pub const MAX_BPS: u16 = 10_000;
+
+pub const MAX_PAYOUT_RANKS: usize = 10;
+
+// session keys are short lived, so a leaked key can't be used for long
+pub const MAX_SESSION_DURATION: i64 = 7 * 24 * 60 * 60;
+
+// reputation gained for every completed challenge, and lost for every failed one
+pub const REPUTATION_PER_WIN: u64 = 10;
+
+pub const REPUTATION_PER_LOSS: u64 = 5;
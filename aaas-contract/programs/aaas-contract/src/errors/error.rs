@@ -38,4 +38,116 @@ pub enum ErrorCode {
     UnAuthorizedOwner,
     #[msg("User has already voted")]
     UserHasAlreadyVoted,
+    #[msg("Proof submission is closed")]
+    ProofSubmissionClosed,
+    #[msg("Proof uri is too long")]
+    ProofUriTooLong,
+    #[msg("User has not submitted the proof")]
+    ProofNotSubmitted,
+    #[msg("Voting has not started yet")]
+    VotingNotStarted,
+    #[msg("Dispute window is not open")]
+    DisputeWindowNotOpen,
+    #[msg("Dispute window is closed")]
+    DisputeWindowClosed,
+    #[msg("User has already disputed the outcome")]
+    AlreadyDisputed,
+    #[msg("User has completed the challenge, nothing to dispute")]
+    NothingToDispute,
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+    #[msg("Challenge is under dispute")]
+    ChallengeUnderDispute,
+    #[msg("Unauthorized arbiter")]
+    UnAuthorizedArbiter,
+    #[msg("Jury size is too large")]
+    JurySizeTooLarge,
+    #[msg("Jury is already drawn")]
+    JuryAlreadyDrawn,
+    #[msg("Jury is not drawn yet")]
+    JuryNotDrawn,
+    #[msg("Challenge does not have a jury")]
+    NoJury,
+    #[msg("Voter is not a juror")]
+    VoterIsNotAJuror,
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    #[msg("Vote tally account is missing")]
+    MissingVoteTally,
+    #[msg("Challenge is full")]
+    ChallengeFull,
+    #[msg("Invite is expired")]
+    InviteExpired,
+    #[msg("Unauthorized creator")]
+    UnAuthorizedCreator,
+    #[msg("Challenge already has participants")]
+    ChallengeHasParticipants,
+    #[msg("Private group is full")]
+    PrivateGroupFull,
+    #[msg("End time can only be extended")]
+    EndTimeCanOnlyBeExtended,
+    #[msg("Fee is too high")]
+    FeeTooHigh,
+    #[msg("Challenge is not finalized")]
+    ChallengeNotFinalized,
+    #[msg("Challenge is already finalized")]
+    ChallengeAlreadyFinalized,
+    #[msg("No fees to claim")]
+    NoFeesToClaim,
+    #[msg("Challenge has no beneficiary")]
+    NoBeneficiary,
+    #[msg("Nothing to sweep")]
+    NothingToSweep,
+    #[msg("Amount must be more than zero")]
+    InvalidAmount,
+    #[msg("Sponsorship is only refunded when nobody completes the challenge")]
+    SponsorshipNotRefundable,
+    #[msg("Invalid payout distribution")]
+    InvalidPayoutDistribution,
+    #[msg("Token accounts don't match the staking mode of the challenge")]
+    MissingTokenAccounts,
+    #[msg("Beneficiary is not a token account of the challenge mint")]
+    InvalidBeneficiary,
+    #[msg("Mint has extensions that are not supported")]
+    UnsupportedMint,
+    #[msg("Stake is out of the range of the challenge")]
+    InvalidStake,
+    #[msg("Forfeit refund is too high")]
+    InvalidForfeitRefund,
+    #[msg("User has forfeited the challenge")]
+    AlreadyForfeited,
+    #[msg("Claim deadline has not passed yet")]
+    ClaimDeadlineNotPassed,
+    #[msg("Challenge still has funds to claim")]
+    ChallengeNotSettled,
+    #[msg("Session key has expired")]
+    SessionKeyExpired,
+    #[msg("Session expiry is out of range")]
+    InvalidSessionExpiry,
+    #[msg("User name must be between 1 and 32 bytes")]
+    InvalidUserName,
+    #[msg("Avatar uri is too long")]
+    AvatarUriTooLong,
+    #[msg("Dispute resolution deadline has passed")]
+    DisputeResolutionExpired,
+    #[msg("Dispute can still be resolved by the arbiter")]
+    DisputeResolutionPending,
+    #[msg("Jury draw is not requested yet")]
+    JuryNotRequested,
+    #[msg("Jury draw is already requested")]
+    JuryAlreadyRequested,
+    #[msg("Slot of the jury draw is not reached yet")]
+    JurySlotNotReached,
+    #[msg("Slot hash of the jury draw has expired, request the draw again")]
+    JurySlotExpired,
+    #[msg("Challenge name must be between 1 and 32 bytes")]
+    InvalidChallengeName,
+    #[msg("Challenge description is too long")]
+    ChallengeDescriptionTooLong,
+    #[msg("Every participant has to be settled before the challenge is finalized")]
+    ParticipantsNotSettled,
+    #[msg("User name account is required when the name changes")]
+    MissingUserNameAccount,
+    #[msg("Claim duration is out of range")]
+    InvalidClaimDuration,
 }
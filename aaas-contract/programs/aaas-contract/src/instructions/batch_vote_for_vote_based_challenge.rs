@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    instructions::{authorize_voter, validate_vote_target, validate_voter},
+    states::{
+        ChallengeAccount, SessionKeyAccount, UserChallengeAccount, VoteEntry, VoteTallyAccount,
+    },
+};
+
+// remaining accounts are the user challenge accounts of the voted users, in the order of the vote entries
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct BatchVoteForVoteBasedChallenge<'info> {
+    // the voter's wallet, or a session key the voter delegated voting to
+    pub signer: Signer<'info>,
+    /// CHECK: wallet the votes are attributed to, authorized in authorize_voter
+    pub voter: UncheckedAccount<'info>,
+    // only when the signer is a session key of the voter
+    #[account(
+        seeds = [b"session_key".as_ref(), voter.key().as_ref(), signer.key().as_ref()],
+        bump = session_key_account.bump
+    )]
+    pub session_key_account: Option<Account<'info, SessionKeyAccount>>,
+    #[account(
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // only participants vote, with challenges with a jury only the drawn jurors
+    #[account(
+        seeds = [b"user_challenge_account".as_ref(), voter.key().as_ref(), challenge_account.key().as_ref()],
+        bump = voter_challenge_account.bump
+    )]
+    pub voter_challenge_account: Account<'info, UserChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump = vote_tally_account.load()?.bump
+    )]
+    pub vote_tally_account: AccountLoader<'info, VoteTallyAccount>,
+}
+
+impl<'info> BatchVoteForVoteBasedChallenge<'info> {
+    pub fn batch_vote_for_vote_based_challenge(
+        &mut self,
+        _challenge_id: u64,
+        votes: Vec<VoteEntry>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let challenge_account = &self.challenge_account;
+        let challenge_address = challenge_account.key();
+        let voter = self.voter.key();
+
+        if !challenge_account.is_vote_verified() {
+            return Err(ErrorCode::InvalidVerificationType.into());
+        }
+        authorize_voter(
+            &self.signer,
+            &self.voter,
+            self.session_key_account.as_deref(),
+        )?;
+
+        // the whole batch is checked before the voting window, so that a malformed batch
+        // is always rejected for its accounts
+        if votes.is_empty() || remaining_accounts.len() != votes.len() {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+        let mut user_challenge_accounts = Vec::with_capacity(votes.len());
+        for (vote, user_challenge_info) in votes.iter().zip(remaining_accounts) {
+            // the user challenge account has to be the PDA of the voted user in this challenge
+            let user_challenge_account =
+                Account::<UserChallengeAccount>::try_from(user_challenge_info)?;
+            let expected_user_challenge_address = Pubkey::create_program_address(
+                &[
+                    b"user_challenge_account".as_ref(),
+                    vote.user_address.as_ref(),
+                    challenge_address.as_ref(),
+                    &[user_challenge_account.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+            if user_challenge_info.key() != expected_user_challenge_address {
+                return Err(ErrorCode::InvalidRemainingAccounts.into());
+            }
+            validate_vote_target(challenge_account, &user_challenge_account, voter)?;
+            user_challenge_accounts.push(user_challenge_account);
+        }
+
+        validate_voter(challenge_account, &self.voter_challenge_account)?;
+
+        let mut vote_tally = self.vote_tally_account.load_mut()?;
+        for (vote, user_challenge_account) in votes.iter().zip(user_challenge_accounts.iter()) {
+            vote_tally.cast_vote(
+                self.voter_challenge_account.participant_index,
+                user_challenge_account.participant_index,
+                vote.is_completed,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::ErrorCode,
     states::{ChallengeAccount, UserAccount, UserChallengeAccount},
+    utils::{stake_account, stake_weighted_share, transfer_from_treasury},
 };
 
 #[derive(Accounts)]
@@ -19,16 +18,18 @@ pub struct ClaimChallenge<'info> {
         bump = challenge_account.bump
     )]
     pub challenge_account: Account<'info, ChallengeAccount>,
-    pub mint: InterfaceAccount<'info, Mint>,
-    #[account(mut)]
-    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = signer,
         associated_token::token_program = token_program,
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         seeds = [b"user_account".as_ref(), signer.key().as_ref()],
@@ -42,7 +43,7 @@ pub struct ClaimChallenge<'info> {
     )]
     pub user_challenge_account: Account<'info, UserChallengeAccount>,
     pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> ClaimChallenge<'info> {
@@ -50,7 +51,6 @@ impl<'info> ClaimChallenge<'info> {
         let challenge_account = &mut self.challenge_account;
         let user_account = &mut self.user_account;
         let user_challenge_account = &mut self.user_challenge_account;
-        let treasury_account = &mut self.treasury_account;
         // check if the user did participated in the challenge
         if !user_challenge_account.is_joined {
             return Err(ErrorCode::UserDidNotParticipate.into());
@@ -59,45 +59,57 @@ impl<'info> ClaimChallenge<'info> {
         if user_challenge_account.money_deposited == 0 {
             return Err(ErrorCode::AlreadyClaimed.into());
         }
-        // check if the challenge is under verification_duration 30min
+        // check if the challenge is under verification
         let current_time = Clock::get()?.unix_timestamp;
-        if challenge_account.end_time + 30 * 60 > current_time {
+        if challenge_account.verification_end_time() > current_time {
             return Err(ErrorCode::ChallengeUnderVerification.into());
         }
-        // check if the user has completed the challenge
-        if !user_challenge_account.is_challenge_completed
-            && user_challenge_account.vote_in_positive < user_challenge_account.vote_in_negative
-        {
-            return Err(ErrorCode::UserHasNotCompletedTheChallenge.into());
+        // check if the outcome can still be disputed
+        if challenge_account.dispute_end_time() > current_time {
+            return Err(ErrorCode::ChallengeUnderDispute.into());
         }
-
-        // refund the money from the treasury account to the user account
-        let transfer_accounts_option = TransferChecked {
-            from: treasury_account.to_account_info(),
-            to: self.user_token_account.to_account_info(),
-            mint: self.mint.to_account_info(),
-            authority: self.treasury_account.to_account_info(),
+        // the outcomes and rewards are settled when the challenge is finalized
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        // winners get back their deposit, the share of the forfeited pool and the share of the
+        // sponsor bonus, the others only the partial refund they earned
+        let amount_to_claim = if user_challenge_account.is_challenge_completed {
+            user_challenge_account.reward = challenge_account.winner_reward(
+                user_challenge_account.participant_index,
+                user_challenge_account.money_deposited,
+            );
+            let sponsor_reward = stake_weighted_share(
+                challenge_account.sponsor_pool,
+                user_challenge_account.money_deposited,
+                challenge_account.winners_stake,
+            );
+            user_challenge_account.money_deposited + user_challenge_account.reward + sponsor_reward
+        } else if user_challenge_account.refund > 0 {
+            user_challenge_account.refund
+        } else {
+            return Err(ErrorCode::UserHasNotCompletedTheChallenge.into());
         };
-
-        let signers_seeds: &[&[&[u8]]] = &[&[
-            b"treasury_account",
-            &challenge_account.challenge_id.to_le_bytes(),
-            &[challenge_account.treasury_bump],
-        ]];
-
-        let cpi_ctx = CpiContext::new(
-            self.token_program.to_account_info(),
-            transfer_accounts_option,
-        )
-        .with_signer(signers_seeds);
-
-        let amount_to_refund = user_challenge_account.money_deposited.clone();
-        transfer_checked(cpi_ctx, amount_to_refund, self.mint.decimals)?;
+        transfer_from_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.signer,
+                self.user_token_account.as_ref(),
+            )?,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+            amount_to_claim,
+        )?;
 
         // update the user account
-        user_account.total_money_withdrawn += user_challenge_account.money_deposited;
+        user_account.total_money_withdrawn += amount_to_claim;
         // update the user challenge account
         user_challenge_account.money_deposited = 0;
+        // update the challenge account
+        challenge_account.pending_claims -= 1;
 
         Ok(())
     }
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, ProgramState},
+    utils::{stake_account, transfer_from_treasury},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct ClaimFees<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    /// CHECK: receives the platform fee of native challenges, or owns its token account
+    #[account(mut, address = state.fee_recipient)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: receives the creator fee of native challenges, or owns its token account
+    #[account(mut, address = challenge_account.creator)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimFees<'info> {
+    pub fn claim_fees(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        if challenge_account.platform_fee == 0 && challenge_account.creator_fee == 0 {
+            return Err(ErrorCode::NoFeesToClaim.into());
+        }
+
+        if challenge_account.platform_fee > 0 {
+            transfer_from_treasury(
+                challenge_account,
+                &self.treasury_account,
+                &stake_account(
+                    challenge_account,
+                    &self.fee_recipient,
+                    self.fee_recipient_token_account.as_ref(),
+                )?,
+                self.mint.as_ref(),
+                self.token_program.as_ref(),
+                &self.system_program,
+                challenge_account.platform_fee,
+            )?;
+            challenge_account.platform_fee = 0;
+        }
+        if challenge_account.creator_fee > 0 {
+            transfer_from_treasury(
+                challenge_account,
+                &self.treasury_account,
+                &stake_account(
+                    challenge_account,
+                    &self.creator,
+                    self.creator_token_account.as_ref(),
+                )?,
+                self.mint.as_ref(),
+                self.token_program.as_ref(),
+                &self.system_program,
+                challenge_account.creator_fee,
+            )?;
+            challenge_account.creator_fee = 0;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, ProgramState, VoteTallyAccount},
+    utils::{close_treasury, stake_account},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct CloseChallenge<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    /// CHECK: receives what is left in the treasury of native challenges, or owns their token account
+    #[account(mut, address = state.fee_recipient)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // only for vote verified challenges
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump = vote_tally_account.load()?.bump
+    )]
+    pub vote_tally_account: Option<AccountLoader<'info, VoteTallyAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> CloseChallenge<'info> {
+    pub fn close_challenge(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &self.challenge_account;
+        require_keys_eq!(
+            self.signer.key(),
+            challenge_account.creator,
+            ErrorCode::UnAuthorizedCreator
+        );
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        // the user challenge and sponsor accounts would be tied to a new challenge reusing the id
+        if challenge_account.closed_participants < challenge_account.total_participants {
+            return Err(ErrorCode::ChallengeHasParticipants.into());
+        }
+        if challenge_account.open_sponsorships > 0 {
+            return Err(ErrorCode::ChallengeNotSettled.into());
+        }
+        // the bonds of the open disputes are returned when they expire, which needs the challenge
+        if challenge_account.open_disputes > 0 {
+            return Err(ErrorCode::ChallengeUnderDispute.into());
+        }
+        // the vote tally is closed along with the challenge
+        if challenge_account.is_vote_verified() && self.vote_tally_account.is_none() {
+            return Err(ErrorCode::MissingVoteTally.into());
+        }
+
+        // a swept treasury is already closed
+        if challenge_account.is_treasury_closed {
+            return Ok(());
+        }
+        // everyone has to be paid before the treasury is closed
+        if challenge_account.pending_claims > 0
+            || challenge_account.platform_fee > 0
+            || challenge_account.creator_fee > 0
+            || challenge_account.beneficiary_amount > 0
+        {
+            return Err(ErrorCode::ChallengeNotSettled.into());
+        }
+        // what is left is the dust of the reward splits, or the forfeited pool when nobody won,
+        // it goes to the fee recipient so the creator never takes more than the creator fee
+        close_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.fee_recipient,
+                self.fee_recipient_token_account.as_ref(),
+            )?,
+            &self.signer,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, SponsorAccount},
+};
+
+// anyone can close the account of a sponsor whose bonus went to the winners
+#[derive(Accounts)]
+#[instruction(challenge_id: u64, sponsor_address: Pubkey)]
+pub struct CloseSponsor<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [b"sponsor_account".as_ref(), challenge_account.key().as_ref(), sponsor_address.as_ref()],
+        bump = sponsor_account.bump
+    )]
+    pub sponsor_account: Account<'info, SponsorAccount>,
+    #[account(mut, address = sponsor_address)]
+    pub sponsor: SystemAccount<'info>,
+}
+
+impl<'info> CloseSponsor<'info> {
+    pub fn close_sponsor(&mut self, _challenge_id: u64, _sponsor_address: Pubkey) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        // without winners the sponsor gets the bonus back through the refund
+        if challenge_account.total_winners == 0 && !challenge_account.is_treasury_closed {
+            return Err(ErrorCode::ChallengeNotSettled.into());
+        }
+
+        challenge_account.open_sponsorships -= 1;
+
+        Ok(())
+    }
+}
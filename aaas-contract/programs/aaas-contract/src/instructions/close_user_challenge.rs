@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, DisputeStatus, UserChallengeAccount},
+};
+
+// anyone can close a settled account, so the creator can close the challenge afterwards
+#[derive(Accounts)]
+#[instruction(challenge_id: u64, user_address: Pubkey)]
+pub struct CloseUserChallenge<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"user_challenge_account".as_ref(), user_address.as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    #[account(mut, address = user_challenge_account.rent_payer)]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+impl<'info> CloseUserChallenge<'info> {
+    pub fn close_user_challenge(
+        &mut self,
+        _challenge_id: u64,
+        _user_address: Pubkey,
+    ) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        // the account holds the outcome until the challenge is finalized
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        // an open dispute is expired with this account, so its bond can't get stuck
+        if self.user_challenge_account.dispute_status == DisputeStatus::Open {
+            return Err(ErrorCode::ChallengeUnderDispute.into());
+        }
+        // the payout has to be claimed first, unless it has been swept
+        if self.user_challenge_account.has_payout() && !challenge_account.is_treasury_closed {
+            return Err(ErrorCode::ChallengeNotSettled.into());
+        }
+
+        challenge_account.closed_participants += 1;
+
+        Ok(())
+    }
+}
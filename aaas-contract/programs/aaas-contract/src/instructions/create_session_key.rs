@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_SESSION_DURATION,
+    errors::ErrorCode,
+    states::{SessionKeyAccount, SessionScope},
+};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    // creating the session key again extends its expiry
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + SessionKeyAccount::INIT_SPACE,
+        seeds = [b"session_key".as_ref(), signer.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub session_key_account: Account<'info, SessionKeyAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSessionKey<'info> {
+    pub fn create_session_key(
+        &mut self,
+        delegate: Pubkey,
+        expiry: i64,
+        bumps: &CreateSessionKeyBumps,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        if expiry <= current_time || expiry > current_time + MAX_SESSION_DURATION {
+            return Err(ErrorCode::InvalidSessionExpiry.into());
+        }
+
+        let session_key_account = &mut self.session_key_account;
+        session_key_account.wallet = self.signer.key();
+        session_key_account.delegate = delegate;
+        session_key_account.scope = SessionScope::Voting;
+        session_key_account.expiry = expiry;
+        session_key_account.bump = bumps.session_key_account;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hashv, sysvar::slot_hashes},
+};
+
+use crate::{errors::ErrorCode, states::ChallengeAccount};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct DrawJury<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    /// CHECK: only the hash of the requested slot is read from the sysvar data
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+impl<'info> DrawJury<'info> {
+    pub fn draw_jury(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if challenge_account.rules.jury_size == 0 {
+            return Err(ErrorCode::NoJury.into());
+        }
+        if challenge_account.is_jury_drawn {
+            return Err(ErrorCode::JuryAlreadyDrawn.into());
+        }
+        // jury is drawn from the hash of the slot committed by request_jury,
+        // until the verification is over
+        let clock = Clock::get()?;
+        if challenge_account.verification_end_time() < clock.unix_timestamp {
+            return Err(ErrorCode::ChallengeVerificationTimeEnded.into());
+        }
+        if challenge_account.jury_slot == 0 {
+            return Err(ErrorCode::JuryNotRequested.into());
+        }
+        if challenge_account.jury_slot >= clock.slot {
+            return Err(ErrorCode::JurySlotNotReached.into());
+        }
+        let data = self.slot_hashes.try_borrow_data()?;
+        let Some(slot_hash) = find_slot_hash(&data, challenge_account.jury_slot) else {
+            return Err(ErrorCode::JurySlotExpired.into());
+        };
+        drop(data);
+
+        let total_participants = challenge_account.total_participants;
+        let jury_size = challenge_account.rules.jury_size as u64;
+        let mut jurors = Vec::with_capacity(jury_size as usize);
+
+        if total_participants <= jury_size {
+            // everyone is a juror in small challenges
+            jurors.extend(0..total_participants);
+        } else {
+            let mut nonce: u64 = 0;
+            while (jurors.len() as u64) < jury_size {
+                let hash = hashv(&[
+                    &slot_hash,
+                    &challenge_account.challenge_id.to_le_bytes(),
+                    &nonce.to_le_bytes(),
+                ]);
+                let random = u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap());
+                let juror = random % total_participants;
+                if !jurors.contains(&juror) {
+                    jurors.push(juror);
+                }
+                nonce += 1;
+            }
+        }
+
+        challenge_account.jurors = jurors;
+        challenge_account.is_jury_drawn = true;
+
+        Ok(())
+    }
+}
+
+// slot hashes data is laid out as [len: u64, (slot: u64, hash: [u8; 32])...], newest first,
+// skipped slots have no entry so the hash of the first slot produced from `slot` is used,
+// as long as the sysvar still reaches back to `slot`
+fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(data.get(..8)?.try_into().ok()?) as usize;
+    let mut slot_hash = None;
+    for entry in data[8..].chunks_exact(40).take(len) {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if entry_slot < slot {
+            return slot_hash;
+        }
+        slot_hash = Some(entry[8..].try_into().unwrap());
+        if entry_slot == slot {
+            return slot_hash;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_hashes_data(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for &slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn finds_the_hash_of_the_slot() {
+        let data = slot_hashes_data(&[12, 11, 10, 9]);
+        assert_eq!(find_slot_hash(&data, 10), Some([10; 32]));
+    }
+
+    #[test]
+    fn uses_the_next_slot_when_the_slot_is_skipped() {
+        let data = slot_hashes_data(&[12, 11, 9]);
+        assert_eq!(find_slot_hash(&data, 10), Some([11; 32]));
+    }
+
+    #[test]
+    fn fails_when_the_slot_has_left_the_sysvar() {
+        let data = slot_hashes_data(&[12, 11]);
+        assert_eq!(find_slot_hash(&data, 10), None);
+    }
+}
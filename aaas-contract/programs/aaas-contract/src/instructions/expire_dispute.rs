@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, DisputeAccount, DisputeStatus, UserChallengeAccount},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64, user_address: Pubkey)]
+pub struct ExpireDispute<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"user_challenge_account".as_ref(), user_address.as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    // the arbiter didn't judge the dispute, so the bond goes back to the participant
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"dispute_account".as_ref(), challenge_account.key().as_ref(), user_address.as_ref()],
+        bump = dispute_account.bump
+    )]
+    pub dispute_account: Account<'info, DisputeAccount>,
+    #[account(mut, address = user_address)]
+    pub user: SystemAccount<'info>,
+}
+
+impl<'info> ExpireDispute<'info> {
+    pub fn expire_dispute(&mut self, _challenge_id: u64, _user_address: Pubkey) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+        let user_challenge_account = &mut self.user_challenge_account;
+
+        if user_challenge_account.dispute_status != DisputeStatus::Open {
+            return Err(ErrorCode::DisputeNotOpen.into());
+        }
+        if challenge_account.dispute_resolution_deadline() >= Clock::get()?.unix_timestamp {
+            return Err(ErrorCode::DisputeResolutionPending.into());
+        }
+
+        // the outcome of the verification stands
+        user_challenge_account.dispute_status = DisputeStatus::Rejected;
+        challenge_account.open_disputes -= 1;
+
+        Ok(())
+    }
+}
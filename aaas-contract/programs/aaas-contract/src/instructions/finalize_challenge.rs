@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, states::ChallengeAccount, utils::split_forfeited_pool};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct FinalizeChallenge<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+}
+
+impl<'info> FinalizeChallenge<'info> {
+    pub fn finalize_challenge(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeAlreadyFinalized.into());
+        }
+        let current_time = Clock::get()?.unix_timestamp;
+        challenge_account.require_outcomes_final(current_time)?;
+        // the totals are only complete once every participant is settled
+        if challenge_account.settled_participants != challenge_account.total_participants {
+            return Err(ErrorCode::ParticipantsNotSettled.into());
+        }
+
+        // the rest of the forfeited pool is donated in charity challenges,
+        // otherwise the winners claim their share of it
+        let split = split_forfeited_pool(
+            challenge_account.forfeited_pool,
+            challenge_account.platform_fee_bps,
+            challenge_account.rules.creator_fee_bps,
+        );
+        if challenge_account.beneficiary.is_some() {
+            challenge_account.beneficiary_amount = split.distributable_pool;
+        }
+
+        challenge_account.is_finalized = true;
+        challenge_account.platform_fee = split.platform_fee;
+        challenge_account.creator_fee = split.creator_fee;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::MAX_BPS,
+    errors::ErrorCode,
+    states::{ChallengeAccount, UserAccount, UserChallengeAccount},
+    utils::{stake_account, transfer_from_treasury},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct ForfeitChallenge<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"user_account".as_ref(), signer.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    #[account(
+        mut,
+        seeds = [b"user_challenge_account".as_ref(), signer.key().as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> ForfeitChallenge<'info> {
+    pub fn forfeit_challenge(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+        let user_account = &mut self.user_account;
+        let user_challenge_account = &mut self.user_challenge_account;
+
+        // check if the user did participated in the challenge
+        if !user_challenge_account.is_joined {
+            return Err(ErrorCode::UserDidNotParticipate.into());
+        }
+        if user_challenge_account.has_forfeited {
+            return Err(ErrorCode::AlreadyForfeited.into());
+        }
+        // participants can only quit while the challenge is running
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.start_time > current_time {
+            return Err(ErrorCode::ChallengeNotStarted.into());
+        }
+        if challenge_account.end_time <= current_time {
+            return Err(ErrorCode::ChallengeEnded.into());
+        }
+
+        // the refund goes back to the user, the penalty stays in the pool and is forfeited
+        let refund = (user_challenge_account.money_deposited as u128
+            * challenge_account.rules.forfeit_refund_bps as u128
+            / MAX_BPS as u128) as u64;
+        if refund > 0 {
+            transfer_from_treasury(
+                challenge_account,
+                &self.treasury_account,
+                &stake_account(
+                    challenge_account,
+                    &self.signer,
+                    self.user_token_account.as_ref(),
+                )?,
+                self.mint.as_ref(),
+                self.token_program.as_ref(),
+                &self.system_program,
+                refund,
+            )?;
+        }
+
+        // update the user account
+        user_account.total_money_withdrawn += refund;
+        // update the user challenge account
+        user_challenge_account.money_deposited -= refund;
+        user_challenge_account.has_forfeited = true;
+        // update the challenge account
+        challenge_account.money_pool -= refund;
+
+        Ok(())
+    }
+}
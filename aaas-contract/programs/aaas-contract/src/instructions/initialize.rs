@@ -30,6 +30,9 @@ impl<'info> Initialize<'info> {
         
         let state = &mut self.state;
         state.owner = *self.signer.key; // Store the deployer's key
+        state.arbiter = *self.signer.key; // owner resolves the disputes until an arbiter is set
+        state.platform_fee_bps = 0;
+        state.fee_recipient = *self.signer.key;
         state.bump = bumps.state;
         Ok(()) 
     }
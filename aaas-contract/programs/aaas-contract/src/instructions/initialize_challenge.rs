@@ -1,9 +1,17 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
+    constants::{
+        MAX_BPS, MAX_CLAIM_DURATION, MAX_JURY_SIZE, MAX_PAYOUT_RANKS, MAX_PRIVATE_GROUP_LEN,
+        MIN_CLAIM_DURATION,
+    },
     errors::ErrorCode,
-    states::{ChallengeAccount, ChallengeInformation, ChallengeType},
+    states::{ChallengeAccount, ChallengeRules, ChallengeType, ProgramState, VoteTallyAccount},
+    utils::validate_mint,
 };
 
 #[derive(Accounts)]
@@ -19,7 +27,13 @@ pub struct InitializeChallenge<'info> {
         bump
     )]
     pub challenge_account: Account<'info, ChallengeAccount>,
-    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
     #[account(
         init,
         payer = signer,
@@ -28,12 +42,32 @@ pub struct InitializeChallenge<'info> {
         seeds = [b"treasury_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // only for native challenges, the treasury holds the lamports itself
+    #[account(
+        mut,
+        seeds = [b"treasury_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub native_treasury_account: Option<SystemAccount<'info>>,
+    // only for vote verified challenges
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + std::mem::size_of::<VoteTallyAccount>(),
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump
+    )]
+    pub vote_tally_account: Option<AccountLoader<'info, VoteTallyAccount>>,
+    /// CHECK: only for charity challenges, the token account of the challenge mint receiving
+    /// the forfeited pool, or a wallet for native challenges
+    pub beneficiary: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> InitializeChallenge<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_challenge(
         &mut self,
         challenge_id: u64,
@@ -45,12 +79,21 @@ impl<'info> InitializeChallenge<'info> {
         money_per_participant: u64,
         is_private: bool,
         private_group: Vec<Pubkey>,
+        rules: ChallengeRules,
+        is_native: bool,
         bumps: &InitializeChallengeBumps,
     ) -> Result<()> {
-        // check if the challenge is private and the private group is empty
-        if is_private && private_group.is_empty() {
+        // check if the challenge is private and nobody is allowed to join
+        if is_private
+            && private_group.is_empty()
+            && rules.allowlist_root.is_none()
+            && !rules.invite_only
+        {
             return Err(ErrorCode::PrivateGroupEmpty.into());
         }
+        if private_group.len() > MAX_PRIVATE_GROUP_LEN {
+            return Err(ErrorCode::PrivateGroupFull.into());
+        }
         // check start time is in the future
         let current_time = Clock::get()?.unix_timestamp;
         if start_time <= current_time {
@@ -60,24 +103,128 @@ impl<'info> InitializeChallenge<'info> {
         if end_time <= start_time {
             return Err(ErrorCode::EndTimeBeforeStartTime.into());
         }
+        // proofs and juries are only for challenges judged by the community
+        if (rules.requires_proof || rules.jury_size > 0)
+            && matches!(challenge_type, ChallengeType::GoogleFit { .. })
+        {
+            return Err(ErrorCode::InvalidVerificationType.into());
+        }
+        if rules.jury_size > MAX_JURY_SIZE {
+            return Err(ErrorCode::JurySizeTooLarge.into());
+        }
+        // ranked and proportional payouts use the scores of the verification service
+        if (!rules.payout_distribution.is_empty() || rules.is_proportional)
+            && !matches!(challenge_type, ChallengeType::GoogleFit { .. })
+        {
+            return Err(ErrorCode::InvalidVerificationType.into());
+        }
+        // the distribution table has to cover the whole pool
+        if !rules.payout_distribution.is_empty()
+            && (rules.payout_distribution.len() > MAX_PAYOUT_RANKS
+                || rules.payout_distribution.contains(&0)
+                || rules
+                    .payout_distribution
+                    .iter()
+                    .map(|&share| share as u32)
+                    .sum::<u32>()
+                    != MAX_BPS as u32)
+        {
+            return Err(ErrorCode::InvalidPayoutDistribution.into());
+        }
+        if rules.max_stake != 0 && rules.max_stake < money_per_participant {
+            return Err(ErrorCode::InvalidStake.into());
+        }
+        if rules.forfeit_refund_bps > MAX_BPS {
+            return Err(ErrorCode::InvalidForfeitRefund.into());
+        }
+        if !(MIN_CLAIM_DURATION..=MAX_CLAIM_DURATION).contains(&rules.claim_duration) {
+            return Err(ErrorCode::InvalidClaimDuration.into());
+        }
+        // fees can't take more than the forfeited pool
+        if rules.creator_fee_bps as u32 + self.state.platform_fee_bps as u32 > MAX_BPS as u32 {
+            return Err(ErrorCode::FeeTooHigh.into());
+        }
+        // the treasury is a token account, or a system account for native challenges
+        if is_native != self.native_treasury_account.is_some()
+            || is_native == self.treasury_account.is_some()
+        {
+            return Err(ErrorCode::MissingTokenAccounts.into());
+        }
+        if let Some(mint) = &self.mint {
+            validate_mint(mint)?;
+        }
+        // votes of vote verified challenges are stored in the vote tally
+        let is_vote_verified = !matches!(challenge_type, ChallengeType::GoogleFit { .. });
+        if is_vote_verified != self.vote_tally_account.is_some() {
+            return Err(ErrorCode::MissingVoteTally.into());
+        }
 
         let challenge_account = &mut self.challenge_account;
         challenge_account.challenge_id = challenge_id;
-        challenge_account.challenge_information = ChallengeInformation {
-            challenge_type,
-            challenge_name,
-            challenge_description,
-        };
+        challenge_account.creator = self.signer.key();
+        let challenge_information = &mut challenge_account.challenge_information;
+        challenge_information.challenge_type = challenge_type;
+        challenge_information.set_challenge_name(challenge_name)?;
+        challenge_information.set_challenge_description(challenge_description)?;
+        challenge_account.rules = rules;
         challenge_account.start_time = start_time;
         challenge_account.end_time = end_time;
         challenge_account.total_participants = 0;
         challenge_account.money_pool = 0;
         challenge_account.money_per_participant = money_per_participant;
-        challenge_account.treasury_account = self.treasury_account.key();
+        challenge_account.is_native = is_native;
+        challenge_account.platform_fee_bps = self.state.platform_fee_bps;
+        if let Some(beneficiary) = &self.beneficiary {
+            if !is_native {
+                if self
+                    .token_program
+                    .as_ref()
+                    .map(|token_program| token_program.key())
+                    != Some(*beneficiary.owner)
+                {
+                    return Err(ErrorCode::InvalidBeneficiary.into());
+                }
+                let beneficiary_token_account =
+                    TokenAccount::try_deserialize(&mut &beneficiary.try_borrow_data()?[..])?;
+                if self.mint.as_ref().map(|mint| mint.key()) != Some(beneficiary_token_account.mint)
+                {
+                    return Err(ErrorCode::InvalidBeneficiary.into());
+                }
+            }
+            challenge_account.beneficiary = Some(beneficiary.key());
+        }
         challenge_account.is_private = is_private;
         challenge_account.private_group = private_group;
         challenge_account.bump = bumps.challenge_account;
-        challenge_account.treasury_bump = bumps.treasury_account;
+
+        if let Some(native_treasury_account) = &self.native_treasury_account {
+            // the treasury is funded with its rent exemption, so deposits of any size can be held
+            let rent_exemption = Rent::get()?.minimum_balance(0);
+            let missing_lamports =
+                rent_exemption.saturating_sub(native_treasury_account.lamports());
+            if missing_lamports > 0 {
+                let transfer_accounts_option = Transfer {
+                    from: self.signer.to_account_info(),
+                    to: native_treasury_account.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(
+                    self.system_program.to_account_info(),
+                    transfer_accounts_option,
+                );
+                transfer(cpi_ctx, missing_lamports)?;
+            }
+            challenge_account.treasury_account = native_treasury_account.key();
+            challenge_account.treasury_bump = bumps.native_treasury_account.unwrap();
+        } else if let Some(treasury_account) = &self.treasury_account {
+            challenge_account.treasury_account = treasury_account.key();
+            challenge_account.treasury_bump = bumps.treasury_account.unwrap();
+        }
+
+        if let Some(vote_tally_account) = &self.vote_tally_account {
+            let mut vote_tally = vote_tally_account.load_init()?;
+            vote_tally.challenge_address = challenge_account.key();
+            vote_tally.bump = bumps.vote_tally_account.unwrap();
+        }
 
         Ok(())
     }
@@ -1,11 +1,14 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as instructions_sysvar};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
+    constants::MAX_VOTE_PARTICIPANTS,
     errors::ErrorCode,
     states::{ChallengeAccount, UserAccount, UserChallengeAccount},
+    utils::{
+        invite_message, stake_account, transfer_to_treasury, verify_ed25519_instruction,
+        verify_merkle_proof,
+    },
 };
 
 #[derive(Accounts)]
@@ -13,58 +16,89 @@ use crate::{
 pub struct JoinChallenge<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
+    // pays the rent of the new account, it can be the signer or a backend sponsoring the user
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
         seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
         bump = challenge_account.bump
     )]
     pub challenge_account: Account<'info, ChallengeAccount>,
-    pub mint: InterfaceAccount<'info, Mint>,
-    #[account(mut)]
-    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = signer,
         associated_token::token_program = token_program,
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    // the user has to be registered before joining
     #[account(
-        init_if_needed,
-        payer = signer,
-        space = 8 + UserAccount::INIT_SPACE,
+        mut,
         seeds = [b"user_account".as_ref(), signer.key().as_ref()],
-        bump
+        bump = user_account.bump
     )]
     pub user_account: Account<'info, UserAccount>,
     #[account(
         init_if_needed,
-        payer = signer,
+        payer = payer,
         space = 8 + UserChallengeAccount::INIT_SPACE,
         seeds = [b"user_challenge_account".as_ref(), signer.key().as_ref(), challenge_account.key().as_ref()],
         bump
     )]
     pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    /// CHECK: instructions sysvar, used to find the creator's signature over an invite
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> JoinChallenge<'info> {
     pub fn join_challenge(
         &mut self,
         _challenge_id: u64,
-        user_name: String,
         description: String,
+        merkle_proof: Vec<[u8; 32]>,
+        invite_expiry: Option<i64>,
+        stake: Option<u64>,
         bumps: &JoinChallengeBumps,
     ) -> Result<()> {
         let challenge_account = &mut self.challenge_account;
         let user_account = &mut self.user_account;
         let user_challenge_account = &mut self.user_challenge_account;
-        let treasury_account = &mut self.treasury_account;
 
-        // check if the challenge is private and the user is in the private group
-        if challenge_account.is_private {
-            if !challenge_account.private_group.contains(&self.signer.key()) {
+        // check if the challenge is private and the user is in the private group or the allowlist,
+        // or has been invited by the creator
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.is_private
+            && !challenge_account.private_group.contains(&self.signer.key())
+            && !challenge_account
+                .rules
+                .allowlist_root
+                .is_some_and(|root| verify_merkle_proof(&root, &self.signer.key(), &merkle_proof))
+        {
+            let Some(invite_expiry) = invite_expiry else {
+                return Err(ErrorCode::UnAuthorized.into());
+            };
+            if invite_expiry < current_time {
+                return Err(ErrorCode::InviteExpired.into());
+            }
+            let message = invite_message(
+                challenge_account.challenge_id,
+                &self.signer.key(),
+                invite_expiry,
+            );
+            if !verify_ed25519_instruction(
+                &self.instructions.to_account_info(),
+                &challenge_account.creator,
+                &message,
+            )? {
                 return Err(ErrorCode::UnAuthorized.into());
             }
         }
@@ -74,47 +108,59 @@ impl<'info> JoinChallenge<'info> {
             return Err(ErrorCode::AlreadyJoined.into());
         }
 
+        // vote verified challenges can't have more participants than the vote tally can hold
+        if challenge_account.is_vote_verified()
+            && challenge_account.total_participants >= MAX_VOTE_PARTICIPANTS as u64
+        {
+            return Err(ErrorCode::ChallengeFull.into());
+        }
+
         // check if the challenge has started
-        let current_time = Clock::get()?.unix_timestamp;
         if challenge_account.start_time <= current_time {
             return Err(ErrorCode::ChallengeStarted.into());
         }
 
-        // transfer the money from the user to treasury account
-        let transfer_accounts_option = TransferChecked {
-            from: self.user_token_account.to_account_info(),
-            to: treasury_account.to_account_info(),
-            mint: self.mint.to_account_info(),
-            authority: self.signer.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(
-            self.token_program.to_account_info(),
-            transfer_accounts_option,
-        );
-        transfer_checked(
-            cpi_ctx,
-            challenge_account.money_per_participant,
-            self.mint.decimals,
+        // the stake defaults to money_per_participant
+        let stake = stake.unwrap_or(challenge_account.money_per_participant);
+        if !challenge_account.is_valid_stake(stake) {
+            return Err(ErrorCode::InvalidStake.into());
+        }
+
+        // transfer the money from the user to treasury account,
+        // the deposit is what the treasury received after the transfer fee
+        let money_deposited = transfer_to_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.signer,
+                self.user_token_account.as_ref(),
+            )?,
+            &self.signer,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+            stake,
         )?;
 
         // update the user challenge account
         user_challenge_account.is_joined = true;
-        user_challenge_account.money_deposited = challenge_account.money_per_participant;
+        user_challenge_account.participant_index = challenge_account.total_participants;
+        user_challenge_account.money_deposited = money_deposited;
         user_challenge_account.challenge_address = challenge_account.key();
         user_challenge_account.user_address = self.signer.key();
         user_challenge_account.is_challenge_completed = false;
         user_challenge_account.bump = bumps.user_challenge_account;
+        user_challenge_account.rent_payer = self.payer.key();
         user_challenge_account.description = description;
 
         // update the user account
-        user_account.user_name = user_name;
         user_account.total_participations += 1;
-        user_account.total_money_deposited += challenge_account.money_per_participant;
-        user_account.bump = bumps.user_account;
+        user_account.total_money_deposited += money_deposited;
 
         // update the challenge account
         challenge_account.total_participants += 1;
-        challenge_account.money_pool += challenge_account.money_per_participant;
+        challenge_account.money_pool += money_deposited;
 
         Ok(())
     }
@@ -4,9 +4,18 @@ pub use initialize::*;
 pub mod initialize_challenge;
 pub use initialize_challenge::*;
 
+pub mod update_challenge;
+pub use update_challenge::*;
+
 pub mod join_challenge;
 pub use join_challenge::*;
 
+pub mod settle_participants;
+pub use settle_participants::*;
+
+pub mod finalize_challenge;
+pub use finalize_challenge::*;
+
 pub mod claim_challenge;
 pub use claim_challenge::*;
 
@@ -15,3 +24,72 @@ pub use update_challenge_status::*;
 
 pub mod vote_for_vote_based_challenge;
 pub use vote_for_vote_based_challenge::*;
+
+pub mod submit_proof;
+pub use submit_proof::*;
+
+pub mod set_arbiter;
+pub use set_arbiter::*;
+
+pub mod open_dispute;
+pub use open_dispute::*;
+
+pub mod resolve_dispute;
+pub use resolve_dispute::*;
+
+pub mod expire_dispute;
+pub use expire_dispute::*;
+
+pub mod request_jury;
+pub use request_jury::*;
+
+pub mod draw_jury;
+pub use draw_jury::*;
+
+pub mod batch_vote_for_vote_based_challenge;
+pub use batch_vote_for_vote_based_challenge::*;
+
+pub mod set_platform_fee;
+pub use set_platform_fee::*;
+
+pub mod claim_fees;
+pub use claim_fees::*;
+
+pub mod sweep_to_beneficiary;
+pub use sweep_to_beneficiary::*;
+
+pub mod sponsor_challenge;
+pub use sponsor_challenge::*;
+
+pub mod refund_sponsor;
+pub use refund_sponsor::*;
+
+pub mod forfeit_challenge;
+pub use forfeit_challenge::*;
+
+pub mod sweep_unclaimed;
+pub use sweep_unclaimed::*;
+
+pub mod close_user_challenge;
+pub use close_user_challenge::*;
+
+pub mod close_challenge;
+pub use close_challenge::*;
+
+pub mod close_sponsor;
+pub use close_sponsor::*;
+
+pub mod create_session_key;
+pub use create_session_key::*;
+
+pub mod revoke_session_key;
+pub use revoke_session_key::*;
+
+pub mod register_user;
+pub use register_user::*;
+
+pub mod update_profile;
+pub use update_profile::*;
+
+pub mod rename_user;
+pub use rename_user::*;
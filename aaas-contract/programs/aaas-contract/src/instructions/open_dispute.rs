@@ -0,0 +1,110 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    constants::DISPUTE_BOND,
+    errors::ErrorCode,
+    states::{
+        ChallengeAccount, DisputeAccount, DisputeStatus, UserChallengeAccount, VoteTallyAccount,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"user_challenge_account".as_ref(), signer.key().as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + DisputeAccount::INIT_SPACE,
+        seeds = [b"dispute_account".as_ref(), challenge_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub dispute_account: Account<'info, DisputeAccount>,
+    // only for vote verified challenges
+    #[account(
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump = vote_tally_account.load()?.bump
+    )]
+    pub vote_tally_account: Option<AccountLoader<'info, VoteTallyAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenDispute<'info> {
+    pub fn open_dispute(&mut self, _challenge_id: u64, bumps: &OpenDisputeBumps) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+        let user_challenge_account = &mut self.user_challenge_account;
+        let dispute_account = &mut self.dispute_account;
+
+        // Check if the user has participated in the challenge
+        if !user_challenge_account.is_joined {
+            return Err(ErrorCode::UserDidNotParticipate.into());
+        }
+        // disputes are opened once the verification is over
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.verification_end_time() > current_time {
+            return Err(ErrorCode::DisputeWindowNotOpen.into());
+        }
+        if challenge_account.dispute_end_time() <= current_time {
+            return Err(ErrorCode::DisputeWindowClosed.into());
+        }
+        // participants who quit have no outcome to dispute
+        if user_challenge_account.has_forfeited {
+            return Err(ErrorCode::AlreadyForfeited.into());
+        }
+        // an outcome can be disputed only once
+        if user_challenge_account.dispute_status != DisputeStatus::None {
+            return Err(ErrorCode::AlreadyDisputed.into());
+        }
+        let vote_tally = self
+            .vote_tally_account
+            .as_ref()
+            .map(|vote_tally_account| vote_tally_account.load())
+            .transpose()?;
+        if challenge_account.is_vote_verified() && vote_tally.is_none() {
+            return Err(ErrorCode::MissingVoteTally.into());
+        }
+        if user_challenge_account.has_completed(
+            challenge_account.rules.requires_proof,
+            vote_tally.as_deref(),
+        ) {
+            return Err(ErrorCode::NothingToDispute.into());
+        }
+
+        // lock the bond in the dispute account
+        let transfer_accounts_option = Transfer {
+            from: self.signer.to_account_info(),
+            to: dispute_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            self.system_program.to_account_info(),
+            transfer_accounts_option,
+        );
+        transfer(cpi_ctx, DISPUTE_BOND)?;
+
+        dispute_account.challenge_address = challenge_account.key();
+        dispute_account.user_address = self.signer.key();
+        dispute_account.bond = DISPUTE_BOND;
+        dispute_account.bump = bumps.dispute_account;
+
+        user_challenge_account.dispute_status = DisputeStatus::Open;
+        challenge_account.open_disputes += 1;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, SponsorAccount},
+    utils::{stake_account, transfer_from_treasury},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct RefundSponsor<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"sponsor_account".as_ref(), challenge_account.key().as_ref(), signer.key().as_ref()],
+        bump = sponsor_account.bump
+    )]
+    pub sponsor_account: Account<'info, SponsorAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> RefundSponsor<'info> {
+    pub fn refund_sponsor(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        // the bonus belongs to the winners when there are any
+        if challenge_account.total_winners > 0 {
+            return Err(ErrorCode::SponsorshipNotRefundable.into());
+        }
+
+        let amount = self.sponsor_account.amount;
+        transfer_from_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.signer,
+                self.sponsor_token_account.as_ref(),
+            )?,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+            amount,
+        )?;
+        challenge_account.sponsor_pool -= amount;
+        challenge_account.open_sponsorships -= 1;
+
+        Ok(())
+    }
+}
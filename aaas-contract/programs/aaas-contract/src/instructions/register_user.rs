@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    states::{UserAccount, UserNameAccount},
+    utils::user_name_seed,
+};
+
+#[derive(Accounts)]
+#[instruction(user_name: String)]
+pub struct RegisterUser<'info> {
+    pub signer: Signer<'info>,
+    // pays the rent of the user account and the name, it can be the signer or a backend sponsoring the user
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserAccount::INIT_SPACE,
+        seeds = [b"user_account".as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    // fails to init when the name is already taken
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserNameAccount::INIT_SPACE,
+        seeds = [b"user_name".as_ref(), &user_name_seed(&user_name)],
+        bump
+    )]
+    pub user_name_account: Account<'info, UserNameAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterUser<'info> {
+    pub fn register_user(
+        &mut self,
+        user_name: String,
+        avatar_uri: String,
+        bio_hash: [u8; 32],
+        bumps: &RegisterUserBumps,
+    ) -> Result<()> {
+        let user_account = &mut self.user_account;
+        user_account.set_user_name(user_name)?;
+        user_account.set_profile(avatar_uri, bio_hash)?;
+        user_account.user_address = self.signer.key();
+        user_account.bump = bumps.user_account;
+
+        let user_name_account = &mut self.user_name_account;
+        user_name_account.user_address = self.signer.key();
+        user_name_account.rent_payer = self.payer.key();
+        user_name_account.bump = bumps.user_name_account;
+
+        Ok(())
+    }
+}
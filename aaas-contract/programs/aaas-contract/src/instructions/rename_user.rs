@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{UserAccount, UserNameAccount},
+    utils::user_name_seed,
+};
+
+#[derive(Accounts)]
+#[instruction(user_name: String)]
+pub struct RenameUser<'info> {
+    pub signer: Signer<'info>,
+    // pays the rent of the new name, it can be the signer or a backend sponsoring the user
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user_account".as_ref(), signer.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+    // the current name is released, unless only its case changes
+    #[account(
+        mut,
+        seeds = [b"user_name".as_ref(), &user_name_seed(&user_account.user_name)],
+        bump = old_user_name_account.bump
+    )]
+    pub old_user_name_account: Account<'info, UserNameAccount>,
+    #[account(mut, address = old_user_name_account.rent_payer)]
+    pub rent_payer: SystemAccount<'info>,
+    // fails to init when the new name is already taken, not passed when only the case changes
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserNameAccount::INIT_SPACE,
+        seeds = [b"user_name".as_ref(), &user_name_seed(&user_name)],
+        bump
+    )]
+    pub new_user_name_account: Option<Account<'info, UserNameAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RenameUser<'info> {
+    pub fn rename_user(&mut self, user_name: String, bumps: &RenameUserBumps) -> Result<()> {
+        // a change of case keeps the same entry of the registry
+        let is_same_entry =
+            user_name_seed(&user_name) == user_name_seed(&self.user_account.user_name);
+        self.user_account.set_user_name(user_name)?;
+        if is_same_entry {
+            return Ok(());
+        }
+
+        let (Some(new_user_name_account), Some(bump)) =
+            (&mut self.new_user_name_account, bumps.new_user_name_account)
+        else {
+            return Err(ErrorCode::MissingUserNameAccount.into());
+        };
+        new_user_name_account.user_address = self.signer.key();
+        new_user_name_account.rent_payer = self.payer.key();
+        new_user_name_account.bump = bump;
+
+        // the rent of the released name goes back to whoever paid it
+        self.old_user_name_account
+            .close(self.rent_payer.to_account_info())?;
+
+        Ok(())
+    }
+}
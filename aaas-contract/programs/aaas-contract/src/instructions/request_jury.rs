@@ -0,0 +1,47 @@
+use anchor_lang::{prelude::*, solana_program::slot_hashes::MAX_ENTRIES};
+
+use crate::{constants::JURY_SLOT_DELAY, errors::ErrorCode, states::ChallengeAccount};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct RequestJury<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+}
+
+impl<'info> RequestJury<'info> {
+    pub fn request_jury(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if challenge_account.rules.jury_size == 0 {
+            return Err(ErrorCode::NoJury.into());
+        }
+        if challenge_account.is_jury_drawn {
+            return Err(ErrorCode::JuryAlreadyDrawn.into());
+        }
+        // jury is requested once the challenge is ended, until the verification is over
+        let clock = Clock::get()?;
+        if challenge_account.end_time > clock.unix_timestamp {
+            return Err(ErrorCode::ChallengeNotEnded.into());
+        }
+        if challenge_account.verification_end_time() < clock.unix_timestamp {
+            return Err(ErrorCode::ChallengeVerificationTimeEnded.into());
+        }
+        // the draw can only be requested again once the hash of the committed slot has
+        // left the slot hashes sysvar without anyone drawing the jury
+        if challenge_account.jury_slot != 0
+            && clock.slot <= challenge_account.jury_slot + MAX_ENTRIES as u64
+        {
+            return Err(ErrorCode::JuryAlreadyRequested.into());
+        }
+
+        challenge_account.jury_slot = clock.slot + JURY_SLOT_DELAY;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{
+        ChallengeAccount, DisputeAccount, DisputeStatus, ProgramState, UserChallengeAccount,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64, user_address: Pubkey)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"user_challenge_account".as_ref(), user_address.as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"dispute_account".as_ref(), challenge_account.key().as_ref(), user_address.as_ref()],
+        bump = dispute_account.bump
+    )]
+    pub dispute_account: Account<'info, DisputeAccount>,
+    #[account(mut, address = user_address)]
+    pub user: SystemAccount<'info>,
+    #[account(mut, address = state.owner)]
+    pub owner: SystemAccount<'info>,
+}
+
+impl<'info> ResolveDispute<'info> {
+    pub fn resolve_dispute(
+        &mut self,
+        _challenge_id: u64,
+        _user_address: Pubkey,
+        is_upheld: bool,
+    ) -> Result<()> {
+        let state = &self.state;
+        if self.signer.key() != state.owner && self.signer.key() != state.arbiter {
+            return Err(ErrorCode::UnAuthorizedArbiter.into());
+        }
+
+        let challenge_account = &mut self.challenge_account;
+        let user_challenge_account = &mut self.user_challenge_account;
+
+        if user_challenge_account.dispute_status != DisputeStatus::Open {
+            return Err(ErrorCode::DisputeNotOpen.into());
+        }
+        // disputes left open past the deadline are rejected with expire_dispute
+        if challenge_account.dispute_resolution_deadline() < Clock::get()?.unix_timestamp {
+            return Err(ErrorCode::DisputeResolutionExpired.into());
+        }
+
+        if is_upheld {
+            // the participant gets the challenge marked as completed and the bond back on close
+            user_challenge_account.is_challenge_completed = true;
+            user_challenge_account.dispute_status = DisputeStatus::Upheld;
+        } else {
+            // the bond is forfeited to the owner, only the rent goes back to the participant
+            let bond = self.dispute_account.bond;
+            self.dispute_account.sub_lamports(bond)?;
+            self.owner.add_lamports(bond)?;
+            user_challenge_account.dispute_status = DisputeStatus::Rejected;
+        }
+        challenge_account.open_disputes -= 1;
+
+        Ok(())
+    }
+}
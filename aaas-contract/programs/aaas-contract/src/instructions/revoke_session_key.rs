@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::states::SessionKeyAccount;
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RevokeSessionKey<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"session_key".as_ref(), signer.key().as_ref(), delegate.as_ref()],
+        bump = session_key_account.bump
+    )]
+    pub session_key_account: Account<'info, SessionKeyAccount>,
+}
+
+impl<'info> RevokeSessionKey<'info> {
+    pub fn revoke_session_key(&mut self, _delegate: Pubkey) -> Result<()> {
+        Ok(())
+    }
+}
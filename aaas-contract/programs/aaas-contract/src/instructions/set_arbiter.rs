@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, states::ProgramState};
+
+#[derive(Accounts)]
+pub struct SetArbiter<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+}
+
+impl<'info> SetArbiter<'info> {
+    pub fn set_arbiter(&mut self, arbiter: Pubkey) -> Result<()> {
+        let state = &mut self.state;
+        require_keys_eq!(self.signer.key(), state.owner, ErrorCode::UnAuthorizedOwner);
+
+        state.arbiter = arbiter;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_BPS, errors::ErrorCode, states::ProgramState};
+
+#[derive(Accounts)]
+pub struct SetPlatformFee<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+}
+
+impl<'info> SetPlatformFee<'info> {
+    pub fn set_platform_fee(&mut self, platform_fee_bps: u16, fee_recipient: Pubkey) -> Result<()> {
+        let state = &mut self.state;
+        require_keys_eq!(self.signer.key(), state.owner, ErrorCode::UnAuthorizedOwner);
+
+        if platform_fee_bps > MAX_BPS {
+            return Err(ErrorCode::FeeTooHigh.into());
+        }
+
+        // only applies to challenges created from now on
+        state.platform_fee_bps = platform_fee_bps;
+        state.fee_recipient = fee_recipient;
+        Ok(())
+    }
+}
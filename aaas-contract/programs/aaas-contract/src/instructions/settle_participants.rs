@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, RankedWinner, UserAccount, UserChallengeAccount, VoteTallyAccount},
+    utils::rank_winner,
+};
+
+// remaining accounts are the user challenge account and the user account of the next
+// participants to settle, in pairs and in the order they joined, so a large challenge
+// is settled over as many transactions as needed
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct SettleParticipants<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // only for vote verified challenges
+    #[account(
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump = vote_tally_account.load()?.bump
+    )]
+    pub vote_tally_account: Option<AccountLoader<'info, VoteTallyAccount>>,
+}
+
+impl<'info> SettleParticipants<'info> {
+    pub fn settle_participants(
+        &mut self,
+        _challenge_id: u64,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeAlreadyFinalized.into());
+        }
+        let current_time = Clock::get()?.unix_timestamp;
+        challenge_account.require_outcomes_final(current_time)?;
+
+        let vote_tally = self
+            .vote_tally_account
+            .as_ref()
+            .map(|vote_tally_account| vote_tally_account.load())
+            .transpose()?;
+        if challenge_account.is_vote_verified() && vote_tally.is_none() {
+            return Err(ErrorCode::MissingVoteTally.into());
+        }
+
+        if remaining_accounts.is_empty() || !remaining_accounts.len().is_multiple_of(2) {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+        let paid_ranks = if challenge_account.beneficiary.is_some() {
+            0
+        } else {
+            challenge_account.rules.payout_distribution.len()
+        };
+        for participant_infos in remaining_accounts.chunks(2) {
+            // the cursor only moves forward, so nobody is settled twice
+            let mut user_challenge_account =
+                Account::<UserChallengeAccount>::try_from(&participant_infos[0])?;
+            if user_challenge_account.challenge_address != challenge_account.key()
+                || !user_challenge_account.is_joined
+                || user_challenge_account.participant_index
+                    != challenge_account.settled_participants
+            {
+                return Err(ErrorCode::InvalidRemainingAccounts.into());
+            }
+
+            // the user account has to be the PDA of the participant
+            let mut user_account = Account::<UserAccount>::try_from(&participant_infos[1])?;
+            let expected_user_address = Pubkey::create_program_address(
+                &[
+                    b"user_account".as_ref(),
+                    user_challenge_account.user_address.as_ref(),
+                    &[user_account.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ErrorCode::InvalidRemainingAccounts)?;
+            if participant_infos[1].key() != expected_user_address {
+                return Err(ErrorCode::InvalidRemainingAccounts.into());
+            }
+
+            // losers forfeit their deposit, or the part of it they didn't earn in
+            // proportional challenges, the rewards are computed from the totals when claimed
+            if user_challenge_account.has_completed(
+                challenge_account.rules.requires_proof,
+                vote_tally.as_deref(),
+            ) {
+                user_account.record_win();
+                user_challenge_account.is_challenge_completed = true;
+                challenge_account.total_winners += 1;
+                challenge_account.winners_stake += user_challenge_account.money_deposited;
+                rank_winner(
+                    &mut challenge_account.ranked_winners,
+                    RankedWinner {
+                        participant_index: user_challenge_account.participant_index,
+                        score: user_challenge_account.score,
+                    },
+                    paid_ranks,
+                );
+            } else {
+                user_account.record_loss();
+                // a completion set by the verification doesn't count for participants who quit
+                user_challenge_account.is_challenge_completed = false;
+
+                // participants who quit already got their refund back
+                let refund = if user_challenge_account.has_forfeited {
+                    0
+                } else {
+                    challenge_account.partial_refund(
+                        user_challenge_account.money_deposited,
+                        user_challenge_account.score,
+                    )
+                };
+                user_challenge_account.refund = refund;
+                challenge_account.forfeited_pool += user_challenge_account.money_deposited - refund;
+            }
+            user_account.exit(&crate::ID)?;
+            if user_challenge_account.has_payout() {
+                challenge_account.pending_claims += 1;
+            }
+            user_challenge_account.exit(&crate::ID)?;
+            challenge_account.settled_participants += 1;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, SponsorAccount},
+    utils::{stake_account, transfer_to_treasury},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct SponsorChallenge<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + SponsorAccount::INIT_SPACE,
+        seeds = [b"sponsor_account".as_ref(), challenge_account.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub sponsor_account: Account<'info, SponsorAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> SponsorChallenge<'info> {
+    pub fn sponsor_challenge(
+        &mut self,
+        _challenge_id: u64,
+        amount: u64,
+        bumps: &SponsorChallengeBumps,
+    ) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+        let sponsor_account = &mut self.sponsor_account;
+
+        if amount == 0 {
+            return Err(ErrorCode::InvalidAmount.into());
+        }
+        // the prize pool is fixed once the challenge is over
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.end_time <= current_time {
+            return Err(ErrorCode::ChallengeEnded.into());
+        }
+
+        // transfer the bonus from the sponsor to treasury account,
+        // only what the treasury received after the transfer fee counts
+        let amount = transfer_to_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.signer,
+                self.sponsor_token_account.as_ref(),
+            )?,
+            &self.signer,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+            amount,
+        )?;
+
+        // a sponsor can top up the bonus several times
+        if sponsor_account.challenge_address == Pubkey::default() {
+            challenge_account.open_sponsorships += 1;
+        }
+        sponsor_account.challenge_address = challenge_account.key();
+        sponsor_account.sponsor_address = self.signer.key();
+        sponsor_account.amount += amount;
+        sponsor_account.bump = bumps.sponsor_account;
+
+        challenge_account.sponsor_pool += amount;
+
+        Ok(())
+    }
+}
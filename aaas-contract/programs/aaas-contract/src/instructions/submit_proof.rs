@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_PROOF_URI_LEN,
+    errors::ErrorCode,
+    states::{ChallengeAccount, UserChallengeAccount},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct SubmitProof<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"user_challenge_account".as_ref(), signer.key().as_ref(), challenge_account.key().as_ref()],
+        bump = user_challenge_account.bump
+    )]
+    pub user_challenge_account: Account<'info, UserChallengeAccount>,
+}
+
+impl<'info> SubmitProof<'info> {
+    pub fn submit_proof(
+        &mut self,
+        _challenge_id: u64,
+        proof_hash: [u8; 32],
+        proof_uri: String,
+    ) -> Result<()> {
+        let challenge_account = &self.challenge_account;
+        let user_challenge_account = &mut self.user_challenge_account;
+
+        // Check if the user has participated in the challenge
+        if !user_challenge_account.is_joined {
+            return Err(ErrorCode::UserDidNotParticipate.into());
+        }
+        // proofs are only judged in vote verified challenges
+        if !challenge_account.is_vote_verified() {
+            return Err(ErrorCode::InvalidVerificationType.into());
+        }
+        // proof can be submitted only between the end of the challenge and the start of voting
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.end_time > current_time {
+            return Err(ErrorCode::ChallengeNotEnded.into());
+        }
+        if challenge_account.verification_start_time() <= current_time {
+            return Err(ErrorCode::ProofSubmissionClosed.into());
+        }
+        if proof_uri.len() > MAX_PROOF_URI_LEN {
+            return Err(ErrorCode::ProofUriTooLong.into());
+        }
+
+        user_challenge_account.is_proof_submitted = true;
+        user_challenge_account.proof_hash = proof_hash;
+        user_challenge_account.proof_uri = proof_uri;
+
+        Ok(())
+    }
+}
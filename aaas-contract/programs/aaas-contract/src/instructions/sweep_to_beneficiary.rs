@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{errors::ErrorCode, states::ChallengeAccount, utils::transfer_from_treasury};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct SweepToBeneficiary<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    /// CHECK: token account receiving the forfeited pool, or a wallet for native challenges
+    #[account(
+        mut,
+        constraint = Some(beneficiary.key()) == challenge_account.beneficiary @ ErrorCode::NoBeneficiary
+    )]
+    pub beneficiary: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> SweepToBeneficiary<'info> {
+    pub fn sweep_to_beneficiary(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        if challenge_account.beneficiary_amount == 0 {
+            return Err(ErrorCode::NothingToSweep.into());
+        }
+
+        transfer_from_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &self.beneficiary,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+            challenge_account.beneficiary_amount,
+        )?;
+        challenge_account.beneficiary_amount = 0;
+
+        Ok(())
+    }
+}
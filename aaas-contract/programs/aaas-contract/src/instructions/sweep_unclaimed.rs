@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    errors::ErrorCode,
+    states::{ChallengeAccount, ProgramState},
+    utils::{close_treasury, stake_account},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct SweepUnclaimed<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"program_owner".as_ref()],
+        bump = state.bump
+    )]
+    pub state: Account<'info, ProgramState>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+    // token accounts are only for challenges staked in a SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    /// CHECK: token account of the treasury, or the treasury itself for native challenges
+    #[account(mut, address = challenge_account.treasury_account)]
+    pub treasury_account: UncheckedAccount<'info>,
+    /// CHECK: receives the unclaimed funds of native challenges, or owns their token account
+    #[account(mut, address = state.fee_recipient)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: paid the rent of the treasury, gets it back when the treasury is closed
+    #[account(mut, address = challenge_account.creator)]
+    pub creator: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> SweepUnclaimed<'info> {
+    pub fn sweep_unclaimed(&mut self, _challenge_id: u64) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+
+        if !challenge_account.is_finalized {
+            return Err(ErrorCode::ChallengeNotFinalized.into());
+        }
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.claim_deadline() > current_time {
+            return Err(ErrorCode::ClaimDeadlineNotPassed.into());
+        }
+
+        // everything left in the treasury goes to the fee recipient, including the unclaimed
+        // fees and the dust of the reward splits
+        close_treasury(
+            challenge_account,
+            &self.treasury_account,
+            &stake_account(
+                challenge_account,
+                &self.fee_recipient,
+                self.fee_recipient_token_account.as_ref(),
+            )?,
+            &self.creator,
+            self.mint.as_ref(),
+            self.token_program.as_ref(),
+            &self.system_program,
+        )?;
+
+        challenge_account.platform_fee = 0;
+        challenge_account.creator_fee = 0;
+        challenge_account.beneficiary_amount = 0;
+        challenge_account.sponsor_pool = 0;
+        challenge_account.is_treasury_closed = true;
+
+        Ok(())
+    }
+}
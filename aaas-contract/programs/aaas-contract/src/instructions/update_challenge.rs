@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_PRIVATE_GROUP_LEN,
+    errors::ErrorCode,
+    states::{ChallengeAccount, ChallengeType},
+};
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct UpdateChallenge<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
+        bump = challenge_account.bump
+    )]
+    pub challenge_account: Account<'info, ChallengeAccount>,
+}
+
+impl<'info> UpdateChallenge<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_challenge(
+        &mut self,
+        _challenge_id: u64,
+        challenge_name: Option<String>,
+        challenge_description: Option<String>,
+        members_to_add: Vec<Pubkey>,
+        members_to_remove: Vec<Pubkey>,
+        end_time: Option<i64>,
+        money_per_participant: Option<u64>,
+        challenge_type: Option<ChallengeType>,
+    ) -> Result<()> {
+        let challenge_account = &mut self.challenge_account;
+        require_keys_eq!(
+            self.signer.key(),
+            challenge_account.creator,
+            ErrorCode::UnAuthorizedCreator
+        );
+
+        // challenge can be updated only before it starts
+        let current_time = Clock::get()?.unix_timestamp;
+        if challenge_account.start_time <= current_time {
+            return Err(ErrorCode::ChallengeStarted.into());
+        }
+
+        // stake and type are what the participants have agreed to when joining
+        if (money_per_participant.is_some() || challenge_type.is_some())
+            && challenge_account.total_participants > 0
+        {
+            return Err(ErrorCode::ChallengeHasParticipants.into());
+        }
+
+        if let Some(challenge_name) = challenge_name {
+            challenge_account
+                .challenge_information
+                .set_challenge_name(challenge_name)?;
+        }
+        if let Some(challenge_description) = challenge_description {
+            challenge_account
+                .challenge_information
+                .set_challenge_description(challenge_description)?;
+        }
+
+        // update the private group
+        let private_group = &mut challenge_account.private_group;
+        private_group.retain(|member| !members_to_remove.contains(member));
+        for member in members_to_add {
+            if !private_group.contains(&member) {
+                private_group.push(member);
+            }
+        }
+        if private_group.len() > MAX_PRIVATE_GROUP_LEN {
+            return Err(ErrorCode::PrivateGroupFull.into());
+        }
+        if challenge_account.is_private
+            && challenge_account.private_group.is_empty()
+            && challenge_account.rules.allowlist_root.is_none()
+            && !challenge_account.rules.invite_only
+        {
+            return Err(ErrorCode::PrivateGroupEmpty.into());
+        }
+
+        // end time can only be extended
+        if let Some(end_time) = end_time {
+            if end_time < challenge_account.end_time {
+                return Err(ErrorCode::EndTimeCanOnlyBeExtended.into());
+            }
+            challenge_account.end_time = end_time;
+        }
+
+        if let Some(money_per_participant) = money_per_participant {
+            let max_stake = challenge_account.rules.max_stake;
+            if max_stake != 0 && max_stake < money_per_participant {
+                return Err(ErrorCode::InvalidStake.into());
+            }
+            challenge_account.money_per_participant = money_per_participant;
+        }
+
+        // the vote tally only exists for vote verified challenges, so the verification can't change
+        if let Some(challenge_type) = challenge_type {
+            let was_vote_verified = challenge_account.is_vote_verified();
+            challenge_account.challenge_information.challenge_type = challenge_type;
+            if challenge_account.is_vote_verified() != was_vote_verified {
+                return Err(ErrorCode::InvalidVerificationType.into());
+            }
+        }
+
+        Ok(())
+    }
+}
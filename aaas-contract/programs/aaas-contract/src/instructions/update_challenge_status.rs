@@ -49,14 +49,18 @@ impl<'info> UpdateChallengeStatus<'info> {
         if !user_challenge_account.is_joined {
             return Err(ErrorCode::UserDidNotParticipate.into());
         }
+        // participants who quit have no outcome to verify
+        if user_challenge_account.has_forfeited {
+            return Err(ErrorCode::AlreadyForfeited.into());
+        }
         // check if the challenge is ended or not
         let current_time = Clock::get()?.unix_timestamp;
         if challenge_account.end_time > current_time {
             return Err(ErrorCode::ChallengeNotEnded.into());
         }
 
-        // Check if the challenge is within the time_end + 30 minutes
-        if challenge_account.end_time + 30 * 60 < current_time {
+        // Check if the challenge is within the verification window
+        if challenge_account.verification_end_time() < current_time {
             return Err(ErrorCode::ChallengeVerificationTimeEnded.into());
         }
 
@@ -72,9 +76,10 @@ impl<'info> UpdateChallengeStatus<'info> {
                     is_completed,
                 },
             ) => {
+                // the score is kept for the ranked and proportional payouts
+                user_challenge_account.score = *score;
                 if *is_completed {
                     user_challenge_account.is_challenge_completed = true;
-                    user_challenge_account.score = *score;
                 }
             }
             _ => return Err(ErrorCode::InvalidVerificationType.into()),
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::states::UserAccount;
+
+// the name is changed with rename_user, as it is held in the user name registry
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user_account".as_ref(), signer.key().as_ref()],
+        bump = user_account.bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+}
+
+impl<'info> UpdateProfile<'info> {
+    pub fn update_profile(&mut self, avatar_uri: String, bio_hash: [u8; 32]) -> Result<()> {
+        self.user_account.set_profile(avatar_uri, bio_hash)
+    }
+}
@@ -3,37 +3,46 @@ use anchor_lang::prelude::*;
 use crate::{
     errors::ErrorCode,
     states::{
-        ChallengeAccount, ChallengeType, ChallengeVerificationType, UserChallengeAccount,
-        VoteAccount,
+        ChallengeAccount, ChallengeType, ChallengeVerificationType, SessionKeyAccount,
+        SessionScope, UserChallengeAccount, VoteTallyAccount,
     },
 };
 
 #[derive(Accounts)]
 #[instruction(challenge_id: u64, user_address: Pubkey)]
 pub struct VoteForVoteBasedChallenge<'info> {
-    #[account(mut)]
+    // the voter's wallet, or a session key the voter delegated voting to
     pub signer: Signer<'info>,
+    /// CHECK: wallet the vote is attributed to, authorized in authorize_voter
+    pub voter: UncheckedAccount<'info>,
+    // only when the signer is a session key of the voter
+    #[account(
+        seeds = [b"session_key".as_ref(), voter.key().as_ref(), signer.key().as_ref()],
+        bump = session_key_account.bump
+    )]
+    pub session_key_account: Option<Account<'info, SessionKeyAccount>>,
     #[account(
-        mut,
         seeds = [b"challenge_account".as_ref(), challenge_id.to_le_bytes().as_ref()],
         bump = challenge_account.bump
     )]
     pub challenge_account: Account<'info, ChallengeAccount>,
     #[account(
-        mut,
         seeds = [b"user_challenge_account".as_ref(), user_address.as_ref(), challenge_account.key().as_ref()],
         bump = user_challenge_account.bump
     )]
     pub user_challenge_account: Account<'info, UserChallengeAccount>,
+    // only participants vote, with challenges with a jury only the drawn jurors
     #[account(
-        init_if_needed,
-        payer = signer,
-        space = 8 + VoteAccount::INIT_SPACE,
-        seeds = [b"vote_account".as_ref(), challenge_account.key().as_ref(), signer.key().as_ref(), user_address.as_ref()],
-        bump
+        seeds = [b"user_challenge_account".as_ref(), voter.key().as_ref(), challenge_account.key().as_ref()],
+        bump = voter_challenge_account.bump
     )]
-    pub vote_account: Account<'info, VoteAccount>,
-    pub system_program: Program<'info, System>,
+    pub voter_challenge_account: Account<'info, UserChallengeAccount>,
+    #[account(
+        mut,
+        seeds = [b"vote_tally_account".as_ref(), challenge_account.key().as_ref()],
+        bump = vote_tally_account.load()?.bump
+    )]
+    pub vote_tally_account: AccountLoader<'info, VoteTallyAccount>,
 }
 
 impl<'info> VoteForVoteBasedChallenge<'info> {
@@ -42,65 +51,29 @@ impl<'info> VoteForVoteBasedChallenge<'info> {
         _challenge_id: u64,
         _user_address: Pubkey,
         challenge_verification: ChallengeVerificationType,
-        bumps: &VoteForVoteBasedChallengeBumps,
     ) -> Result<()> {
-        let challenge_account = &mut self.challenge_account;
-        let user_challenge_account = &mut self.user_challenge_account;
-        let vote_account = &mut self.vote_account;
-
-        // Check if the user has participated in the challenge
-        if !user_challenge_account.is_joined {
-            return Err(ErrorCode::UserDidNotParticipate.into());
-        }
-        // Check if voter is not voting for himself
-        if user_challenge_account.user_address == self.signer.key() {
-            return Err(ErrorCode::VoterIsVotingForHimself.into());
-        }
-        // check if the challenge is ended or not
-        let current_time = Clock::get()?.unix_timestamp;
-        if challenge_account.end_time > current_time {
-            return Err(ErrorCode::ChallengeNotEnded.into());
-        }
+        let challenge_account = &self.challenge_account;
+        let user_challenge_account = &self.user_challenge_account;
 
-        // Check if the challenge is within the time_end + 30 minutes
-        if challenge_account.end_time + 30 * 60 < current_time {
-            return Err(ErrorCode::ChallengeVerificationTimeEnded.into());
-        }
-
-        // check if the user has already voted
-        if vote_account.is_voted {
-            return Err(ErrorCode::UserHasAlreadyVoted.into());
-        }
+        authorize_voter(
+            &self.signer,
+            &self.voter,
+            self.session_key_account.as_deref(),
+        )?;
+        validate_vote_target(challenge_account, user_challenge_account, self.voter.key())?;
+        validate_voter(challenge_account, &self.voter_challenge_account)?;
 
         match challenge_verification {
             ChallengeVerificationType::VoteBased { is_completed } => {
                 // check if the challenge is also vote based
                 match challenge_account.challenge_information.challenge_type {
-                    ChallengeType::VoteBased => {
-                        if is_completed {
-                            user_challenge_account.vote_in_positive += 1;
-                        } else {
-                            user_challenge_account.vote_in_negative += 1;
-                        }
-                        vote_account.is_voted = true;
-                        vote_account.is_completed = is_completed;
-                        vote_account.bump = bumps.vote_account;
-                        vote_account.challenge_address = challenge_account.key();
-                        vote_account.user_address = user_challenge_account.user_address;
-                        vote_account.voter_address = self.signer.key();
-                    }
-                    ChallengeType::Github { commits: _ } => {
-                        if is_completed {
-                            user_challenge_account.vote_in_positive += 1;
-                        } else {
-                            user_challenge_account.vote_in_negative += 1;
-                        }
-                        vote_account.is_voted = true;
-                        vote_account.is_completed = is_completed;
-                        vote_account.bump = bumps.vote_account;
-                        vote_account.challenge_address = challenge_account.key();
-                        vote_account.user_address = user_challenge_account.user_address;
-                        vote_account.voter_address = self.signer.key();
+                    ChallengeType::VoteBased | ChallengeType::Github { commits: _ } => {
+                        let mut vote_tally = self.vote_tally_account.load_mut()?;
+                        vote_tally.cast_vote(
+                            self.voter_challenge_account.participant_index,
+                            user_challenge_account.participant_index,
+                            is_completed,
+                        )?;
                     }
                     _ => return Err(ErrorCode::InvalidVerificationType.into()),
                 }
@@ -111,3 +84,85 @@ impl<'info> VoteForVoteBasedChallenge<'info> {
         Ok(())
     }
 }
+
+// the wallet signs its votes itself, or through a session key it delegated voting to,
+// shared with the batch voting
+pub fn authorize_voter(
+    signer: &Signer,
+    voter: &AccountInfo,
+    session_key_account: Option<&SessionKeyAccount>,
+) -> Result<()> {
+    if signer.key() == voter.key() {
+        return Ok(());
+    }
+    // the seeds of the session key already tie it to the voter and the signer
+    let Some(session_key_account) = session_key_account else {
+        return Err(ErrorCode::UnAuthorized.into());
+    };
+    let current_time = Clock::get()?.unix_timestamp;
+    if !session_key_account.is_valid(SessionScope::Voting, current_time) {
+        return Err(ErrorCode::SessionKeyExpired.into());
+    }
+    Ok(())
+}
+
+// checks on the participant being voted for, shared with the batch voting
+pub fn validate_vote_target(
+    challenge_account: &ChallengeAccount,
+    user_challenge_account: &UserChallengeAccount,
+    voter: Pubkey,
+) -> Result<()> {
+    // Check if the user has participated in the challenge
+    if !user_challenge_account.is_joined {
+        return Err(ErrorCode::UserDidNotParticipate.into());
+    }
+    // Check if voter is not voting for himself
+    if user_challenge_account.user_address == voter {
+        return Err(ErrorCode::VoterIsVotingForHimself.into());
+    }
+    // check if the user has submitted the proof, when the challenge requires it
+    if challenge_account.rules.requires_proof && !user_challenge_account.is_proof_submitted {
+        return Err(ErrorCode::ProofNotSubmitted.into());
+    }
+    Ok(())
+}
+
+// checks on the voting window and the voter, shared with the batch voting
+pub fn validate_voter(
+    challenge_account: &ChallengeAccount,
+    voter_challenge_account: &UserChallengeAccount,
+) -> Result<()> {
+    // check if the challenge is ended or not
+    let current_time = Clock::get()?.unix_timestamp;
+    if challenge_account.end_time > current_time {
+        return Err(ErrorCode::ChallengeNotEnded.into());
+    }
+
+    // voting starts once the proof submission window is over
+    if challenge_account.verification_start_time() > current_time {
+        return Err(ErrorCode::VotingNotStarted.into());
+    }
+
+    // Check if the challenge is within the verification window
+    if challenge_account.verification_end_time() < current_time {
+        return Err(ErrorCode::ChallengeVerificationTimeEnded.into());
+    }
+
+    // only participants vote, so nobody can take over the tally with throwaway wallets,
+    // and when the challenge has a jury only the drawn jurors
+    if !voter_challenge_account.is_joined {
+        return Err(ErrorCode::UserDidNotParticipate.into());
+    }
+    if challenge_account.rules.jury_size > 0 {
+        if !challenge_account.is_jury_drawn {
+            return Err(ErrorCode::JuryNotDrawn.into());
+        }
+        if !challenge_account
+            .jurors
+            .contains(&voter_challenge_account.participant_index)
+        {
+            return Err(ErrorCode::VoterIsNotAJuror.into());
+        }
+    }
+    Ok(())
+}
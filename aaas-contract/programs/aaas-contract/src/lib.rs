@@ -1,8 +1,15 @@
+#![allow(unexpected_cfgs)]
+// only for the IDL handlers of #[program], they call the deprecated AccountInfo::realloc and are
+// emitted in a private module at the crate root, so the allow can't be scoped any narrower
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
+pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod states;
+pub mod utils;
 
 pub use instructions::*;
 pub use states::*;
@@ -20,6 +27,7 @@ pub mod aaas_contract {
     }
 
     // this will be called by the owner of the contract
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_challenge(
         context: Context<InitializeChallenge>,
         challenge_id: u64,
@@ -31,6 +39,8 @@ pub mod aaas_contract {
         money_per_participant: u64,
         is_private: bool,
         private_group: Vec<Pubkey>,
+        rules: ChallengeRules,
+        is_native: bool,
     ) -> Result<()> {
         context.accounts.initialize_challenge(
             challenge_id,
@@ -42,20 +52,123 @@ pub mod aaas_contract {
             money_per_participant,
             is_private,
             private_group,
+            rules,
+            is_native,
             &context.bumps,
         )?;
         Ok(())
     }
 
+    // this will be called by the creator of the challenge, before it starts
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_challenge(
+        context: Context<UpdateChallenge>,
+        challenge_id: u64,
+        challenge_name: Option<String>,
+        challenge_description: Option<String>,
+        members_to_add: Vec<Pubkey>,
+        members_to_remove: Vec<Pubkey>,
+        end_time: Option<i64>,
+        money_per_participant: Option<u64>,
+        challenge_type: Option<ChallengeType>,
+    ) -> Result<()> {
+        context.accounts.update_challenge(
+            challenge_id,
+            challenge_name,
+            challenge_description,
+            members_to_add,
+            members_to_remove,
+            end_time,
+            money_per_participant,
+            challenge_type,
+        )?;
+        Ok(())
+    }
+
+    // this will be called by the user once, before joining any challenge
+    pub fn register_user(
+        context: Context<RegisterUser>,
+        user_name: String,
+        avatar_uri: String,
+        bio_hash: [u8; 32],
+    ) -> Result<()> {
+        context
+            .accounts
+            .register_user(user_name, avatar_uri, bio_hash, &context.bumps)?;
+        Ok(())
+    }
+
+    // this will be called by the user to edit their profile
+    pub fn update_profile(
+        context: Context<UpdateProfile>,
+        avatar_uri: String,
+        bio_hash: [u8; 32],
+    ) -> Result<()> {
+        context.accounts.update_profile(avatar_uri, bio_hash)?;
+        Ok(())
+    }
+
+    // this will be called by the user to change their name, releasing the previous one
+    pub fn rename_user(context: Context<RenameUser>, user_name: String) -> Result<()> {
+        context.accounts.rename_user(user_name, &context.bumps)?;
+        Ok(())
+    }
+
     pub fn join_challenge(
         context: Context<JoinChallenge>,
         challenge_id: u64,
-        user_name: String,
         description: String,
+        merkle_proof: Vec<[u8; 32]>,
+        invite_expiry: Option<i64>,
+        stake: Option<u64>,
+    ) -> Result<()> {
+        context.accounts.join_challenge(
+            challenge_id,
+            description,
+            merkle_proof,
+            invite_expiry,
+            stake,
+            &context.bumps,
+        )?;
+        Ok(())
+    }
+
+    pub fn submit_proof(
+        context: Context<SubmitProof>,
+        challenge_id: u64,
+        proof_hash: [u8; 32],
+        proof_uri: String,
     ) -> Result<()> {
         context
             .accounts
-            .join_challenge(challenge_id, user_name, description, &context.bumps)?;
+            .submit_proof(challenge_id, proof_hash, proof_uri)?;
+        Ok(())
+    }
+
+    // this will be called by the participant to quit a running challenge
+    pub fn forfeit_challenge(context: Context<ForfeitChallenge>, challenge_id: u64) -> Result<()> {
+        context.accounts.forfeit_challenge(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the verification and the disputes are over,
+    // as many times as needed to settle every participant
+    pub fn settle_participants<'info>(
+        context: Context<'_, '_, 'info, 'info, SettleParticipants<'info>>,
+        challenge_id: u64,
+    ) -> Result<()> {
+        context
+            .accounts
+            .settle_participants(challenge_id, context.remaining_accounts)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once every participant is settled
+    pub fn finalize_challenge(
+        context: Context<FinalizeChallenge>,
+        challenge_id: u64,
+    ) -> Result<()> {
+        context.accounts.finalize_challenge(challenge_id)?;
         Ok(())
     }
 
@@ -79,6 +192,18 @@ pub mod aaas_contract {
         Ok(())
     }
 
+    // this can be called by anyone once the challenge is ended, to commit to the slot of the draw
+    pub fn request_jury(context: Context<RequestJury>, challenge_id: u64) -> Result<()> {
+        context.accounts.request_jury(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the requested slot has passed
+    pub fn draw_jury(context: Context<DrawJury>, challenge_id: u64) -> Result<()> {
+        context.accounts.draw_jury(challenge_id)?;
+        Ok(())
+    }
+
     // this will be called by the community members
     pub fn vote_for_vote_based_challenge(
         context: Context<VoteForVoteBasedChallenge>,
@@ -90,9 +215,158 @@ pub mod aaas_contract {
             challenge_id,
             user_address,
             challenge_verification,
-            &context.bumps,
         )?;
 
         Ok(())
     }
+
+    // this will be called by the community members, to vote for many participants at once
+    pub fn batch_vote_for_vote_based_challenge<'info>(
+        context: Context<'_, '_, 'info, 'info, BatchVoteForVoteBasedChallenge<'info>>,
+        challenge_id: u64,
+        votes: Vec<VoteEntry>,
+    ) -> Result<()> {
+        context.accounts.batch_vote_for_vote_based_challenge(
+            challenge_id,
+            votes,
+            context.remaining_accounts,
+        )?;
+        Ok(())
+    }
+
+    // this will be called by the user to let a delegate key vote on their behalf
+    pub fn create_session_key(
+        context: Context<CreateSessionKey>,
+        delegate: Pubkey,
+        expiry: i64,
+    ) -> Result<()> {
+        context
+            .accounts
+            .create_session_key(delegate, expiry, &context.bumps)?;
+        Ok(())
+    }
+
+    // this will be called by the user to revoke a session key before it expires
+    pub fn revoke_session_key(context: Context<RevokeSessionKey>, delegate: Pubkey) -> Result<()> {
+        context.accounts.revoke_session_key(delegate)?;
+        Ok(())
+    }
+
+    pub fn set_arbiter(context: Context<SetArbiter>, arbiter: Pubkey) -> Result<()> {
+        context.accounts.set_arbiter(arbiter)?;
+        Ok(())
+    }
+
+    pub fn set_platform_fee(
+        context: Context<SetPlatformFee>,
+        platform_fee_bps: u16,
+        fee_recipient: Pubkey,
+    ) -> Result<()> {
+        context
+            .accounts
+            .set_platform_fee(platform_fee_bps, fee_recipient)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the challenge is finalized
+    pub fn claim_fees(context: Context<ClaimFees>, challenge_id: u64) -> Result<()> {
+        context.accounts.claim_fees(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once a charity challenge is finalized
+    pub fn sweep_to_beneficiary(
+        context: Context<SweepToBeneficiary>,
+        challenge_id: u64,
+    ) -> Result<()> {
+        context.accounts.sweep_to_beneficiary(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by any wallet to add a bonus to the prize pool
+    pub fn sponsor_challenge(
+        context: Context<SponsorChallenge>,
+        challenge_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        context
+            .accounts
+            .sponsor_challenge(challenge_id, amount, &context.bumps)?;
+        Ok(())
+    }
+
+    // this will be called by the sponsor when nobody completed the challenge
+    pub fn refund_sponsor(context: Context<RefundSponsor>, challenge_id: u64) -> Result<()> {
+        context.accounts.refund_sponsor(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the claim deadline has passed
+    pub fn sweep_unclaimed(context: Context<SweepUnclaimed>, challenge_id: u64) -> Result<()> {
+        context.accounts.sweep_unclaimed(challenge_id)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the payout of the participant is claimed
+    pub fn close_user_challenge(
+        context: Context<CloseUserChallenge>,
+        challenge_id: u64,
+        user_address: Pubkey,
+    ) -> Result<()> {
+        context
+            .accounts
+            .close_user_challenge(challenge_id, user_address)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the bonus of the sponsor went to the winners
+    pub fn close_sponsor(
+        context: Context<CloseSponsor>,
+        challenge_id: u64,
+        sponsor_address: Pubkey,
+    ) -> Result<()> {
+        context
+            .accounts
+            .close_sponsor(challenge_id, sponsor_address)?;
+        Ok(())
+    }
+
+    // this will be called by the creator once everyone is paid
+    pub fn close_challenge(context: Context<CloseChallenge>, challenge_id: u64) -> Result<()> {
+        context.accounts.close_challenge(challenge_id)?;
+        Ok(())
+    }
+
+    // this will be called by the participant to contest the outcome of the verification
+    pub fn open_dispute(context: Context<OpenDispute>, challenge_id: u64) -> Result<()> {
+        context
+            .accounts
+            .open_dispute(challenge_id, &context.bumps)?;
+        Ok(())
+    }
+
+    // this will be called by the owner or the arbiter
+    pub fn resolve_dispute(
+        context: Context<ResolveDispute>,
+        challenge_id: u64,
+        user_address: Pubkey,
+        is_upheld: bool,
+    ) -> Result<()> {
+        context
+            .accounts
+            .resolve_dispute(challenge_id, user_address, is_upheld)?;
+        Ok(())
+    }
+
+    // this can be called by anyone once the arbiter missed the resolution deadline
+    pub fn expire_dispute(
+        context: Context<ExpireDispute>,
+        challenge_id: u64,
+        user_address: Pubkey,
+    ) -> Result<()> {
+        context
+            .accounts
+            .expire_dispute(challenge_id, user_address)?;
+        Ok(())
+    }
 }
@@ -1,24 +1,164 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{
+        DISPUTE_DURATION, DISPUTE_RESOLUTION_DURATION, MAX_CHALLENGE_DESCRIPTION_LEN,
+        MAX_CHALLENGE_NAME_LEN, MAX_PAYOUT_RANKS, MAX_PRIVATE_GROUP_LEN, PROOF_SUBMISSION_DURATION,
+        VERIFICATION_DURATION,
+    },
+    errors::ErrorCode,
+    utils::{proportional_refund, ranked_share, split_forfeited_pool, stake_weighted_share},
+};
+
 #[account]
 #[derive(InitSpace)]
 pub struct ChallengeAccount {
     pub challenge_id: u64,
+    pub creator: Pubkey,
     pub challenge_information: ChallengeInformation,
+    pub rules: ChallengeRules,
     pub start_time: i64,
     pub end_time: i64,
     pub total_participants: u64,
     pub total_votes: u64,
+    pub open_disputes: u64,
+    pub is_jury_drawn: bool,
+    #[max_len(9)]
+    pub jurors: Vec<u64>, // participant indexes of the drawn jurors
+    pub jury_slot: u64, // slot whose hash draws the jury, 0 until the draw is requested
     pub money_pool: u64,
     pub money_per_participant: u64,
+    pub is_native: bool,   // staked in lamports instead of a SPL token
+    pub sponsor_pool: u64, // bonus from the sponsors, split among the winners
+    pub open_sponsorships: u64,
     pub treasury_account: Pubkey,
+    pub platform_fee_bps: u16, // taken from the program state when the challenge is created
+    pub is_finalized: bool,
+    pub settled_participants: u64, // participants are settled in the order they joined
+    #[max_len(MAX_PAYOUT_RANKS)]
+    pub ranked_winners: Vec<RankedWinner>, // top scorers paid by the payout distribution
+    pub total_winners: u64,
+    pub pending_claims: u64, // participants with a payout left to claim
+    pub closed_participants: u64,
+    pub winners_stake: u64, // deposits of the winners, the winnings are weighted by stake
+    pub forfeited_pool: u64,
+    pub platform_fee: u64,           // left to claim
+    pub creator_fee: u64,            // left to claim
+    pub beneficiary: Option<Pubkey>, // token account receiving the forfeited pool instead of the winners
+    pub beneficiary_amount: u64,     // left to sweep
+    pub is_treasury_closed: bool,    // unclaimed funds are swept after the claim deadline
     pub treasury_bump: u8,
     pub is_private: bool,
-    #[max_len(10)]
+    #[max_len(MAX_PRIVATE_GROUP_LEN)]
     pub private_group: Vec<Pubkey>, // this will be used to store the private group of the challenge
     pub bump: u8,
 }
 
+impl ChallengeAccount {
+    // vote verified challenges are judged by the community instead of the verification service
+    pub fn is_vote_verified(&self) -> bool {
+        matches!(
+            self.challenge_information.challenge_type,
+            ChallengeType::Github { .. } | ChallengeType::VoteBased
+        )
+    }
+
+    // vote verified challenges requiring a proof leave room for its submission before voting
+    pub fn verification_start_time(&self) -> i64 {
+        if self.is_vote_verified() && self.rules.requires_proof {
+            self.end_time + PROOF_SUBMISSION_DURATION
+        } else {
+            self.end_time
+        }
+    }
+
+    pub fn verification_end_time(&self) -> i64 {
+        self.verification_start_time() + VERIFICATION_DURATION
+    }
+
+    pub fn dispute_end_time(&self) -> i64 {
+        self.verification_end_time() + DISPUTE_DURATION
+    }
+
+    pub fn dispute_resolution_deadline(&self) -> i64 {
+        self.dispute_end_time() + DISPUTE_RESOLUTION_DURATION
+    }
+
+    pub fn claim_deadline(&self) -> i64 {
+        self.dispute_end_time() + self.rules.claim_duration
+    }
+
+    // outcomes can only be settled once the verification and the disputes are over,
+    // disputes the arbiter didn't resolve in time are rejected
+    pub fn require_outcomes_final(&self, current_time: i64) -> Result<()> {
+        if self.verification_end_time() > current_time {
+            return Err(ErrorCode::ChallengeUnderVerification.into());
+        }
+        if self.dispute_end_time() > current_time
+            || (self.open_disputes > 0 && self.dispute_resolution_deadline() >= current_time)
+        {
+            return Err(ErrorCode::ChallengeUnderDispute.into());
+        }
+        Ok(())
+    }
+
+    pub fn is_valid_stake(&self, stake: u64) -> bool {
+        if self.rules.max_stake == 0 {
+            return stake == self.money_per_participant;
+        }
+        (self.money_per_participant..=self.rules.max_stake).contains(&stake)
+    }
+
+    // the part of the deposit given back to a participant who didn't complete the challenge
+    pub fn partial_refund(&self, money_deposited: u64, score: u64) -> u64 {
+        if !self.rules.is_proportional {
+            return 0;
+        }
+        match self.challenge_information.challenge_type {
+            ChallengeType::GoogleFit { steps } => {
+                proportional_refund(money_deposited, score, steps)
+            }
+            _ => 0,
+        }
+    }
+
+    // share of the forfeited pool of a winner, from the totals of the settlement
+    pub fn winner_reward(&self, participant_index: u64, money_deposited: u64) -> u64 {
+        // the forfeited pool is donated in charity challenges
+        if self.beneficiary.is_some() {
+            return 0;
+        }
+        let distributable_pool = split_forfeited_pool(
+            self.forfeited_pool,
+            self.platform_fee_bps,
+            self.rules.creator_fee_bps,
+        )
+        .distributable_pool;
+        let payout_distribution = &self.rules.payout_distribution;
+        if payout_distribution.is_empty() {
+            return stake_weighted_share(distributable_pool, money_deposited, self.winners_stake);
+        }
+        self.ranked_winners
+            .iter()
+            .position(|ranked_winner| ranked_winner.participant_index == participant_index)
+            .map(|rank| {
+                ranked_share(
+                    distributable_pool,
+                    payout_distribution,
+                    self.ranked_winners.len(),
+                    rank,
+                )
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RankedWinner {
+    pub participant_index: u64,
+    pub score: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum ChallengeType {
     #[doc = "this will be verified by the off-chain verification service"]
@@ -32,12 +172,56 @@ pub enum ChallengeType {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct ChallengeInformation {
     pub challenge_type: ChallengeType,
-    #[max_len(32)]
+    #[max_len(MAX_CHALLENGE_NAME_LEN)]
     pub challenge_name: String,
-    #[max_len(256)]
+    #[max_len(MAX_CHALLENGE_DESCRIPTION_LEN)]
     pub challenge_description: String,
 }
 
+impl ChallengeInformation {
+    // shared by the creation and the updates of the challenge
+    pub fn set_challenge_name(&mut self, challenge_name: String) -> Result<()> {
+        if challenge_name.is_empty() || challenge_name.len() > MAX_CHALLENGE_NAME_LEN {
+            return Err(ErrorCode::InvalidChallengeName.into());
+        }
+        self.challenge_name = challenge_name;
+        Ok(())
+    }
+
+    pub fn set_challenge_description(&mut self, challenge_description: String) -> Result<()> {
+        if challenge_description.len() > MAX_CHALLENGE_DESCRIPTION_LEN {
+            return Err(ErrorCode::ChallengeDescriptionTooLong.into());
+        }
+        self.challenge_description = challenge_description;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChallengeRules {
+    #[doc = "votes are only accepted for participants who submitted a proof"]
+    pub requires_proof: bool,
+    #[doc = "number of jurors drawn from the participants to vote, 0 lets every participant vote"]
+    pub jury_size: u8,
+    #[doc = "merkle root of the addresses allowed to join a private challenge, next to the private group"]
+    pub allowlist_root: Option<[u8; 32]>,
+    #[doc = "fee of the creator in basis points, taken from the forfeited stakes of the losers"]
+    pub creator_fee_bps: u16,
+    #[doc = "shares of the forfeited pool in basis points for the top scorers of monitored challenges, empty splits it equally among the winners"]
+    #[max_len(MAX_PAYOUT_RANKS)]
+    pub payout_distribution: Vec<u16>,
+    #[doc = "participants of monitored challenges who fall short get back their deposit in proportion to their score"]
+    pub is_proportional: bool,
+    #[doc = "participants can stake anything between money_per_participant and this amount, 0 keeps the stake fixed"]
+    pub max_stake: u64,
+    #[doc = "part of the deposit in basis points given back to participants who quit during the challenge"]
+    pub forfeit_refund_bps: u16,
+    #[doc = "private challenges can only be joined with an invite signed by the creator, without a private group or an allowlist"]
+    pub invite_only: bool,
+    #[doc = "seconds given to the winners to claim once the disputes are over, the rest is swept afterwards"]
+    pub claim_duration: i64,
+}
+
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub enum ChallengeVerificationType {
     #[doc = "this will be verified by the off-chain verification service"]
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct DisputeAccount {
+    pub challenge_address: Pubkey,
+    pub user_address: Pubkey,
+    pub bond: u64,
+    pub bump: u8,
+}
@@ -12,3 +12,11 @@ pub use user::*;
 pub mod vote;
 pub use vote::*;
 
+pub mod dispute;
+pub use dispute::*;
+
+pub mod sponsor;
+pub use sponsor::*;
+
+pub mod session;
+pub use session::*;
@@ -4,5 +4,8 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct ProgramState {
     pub owner: Pubkey,
+    pub arbiter: Pubkey,
+    pub platform_fee_bps: u16,
+    pub fee_recipient: Pubkey,
     pub bump: u8,
 }
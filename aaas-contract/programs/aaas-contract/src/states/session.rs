@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKeyAccount {
+    pub wallet: Pubkey,
+    pub delegate: Pubkey,
+    pub scope: SessionScope,
+    pub expiry: i64,
+    pub bump: u8,
+}
+
+impl SessionKeyAccount {
+    pub fn is_valid(&self, scope: SessionScope, current_time: i64) -> bool {
+        self.scope == scope && self.expiry >= current_time
+    }
+}
+
+// the instructions a session key can sign for in place of the wallet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SessionScope {
+    Voting,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SponsorAccount {
+    pub challenge_address: Pubkey,
+    pub sponsor_address: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
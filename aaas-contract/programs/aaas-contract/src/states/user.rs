@@ -1,14 +1,75 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{
+        MAX_AVATAR_URI_LEN, MAX_PROOF_URI_LEN, MAX_USER_NAME_LEN, REPUTATION_PER_LOSS,
+        REPUTATION_PER_WIN,
+    },
+    errors::ErrorCode,
+    states::VoteTallyAccount,
+};
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserAccount {
-    #[max_len(32)]
+    #[max_len(MAX_USER_NAME_LEN)]
     pub user_name: String,
+    #[max_len(MAX_AVATAR_URI_LEN)]
+    pub avatar_uri: String,
+    pub bio_hash: [u8; 32], // hash of the bio stored off-chain
     pub user_address: Pubkey,
     pub total_participations: u64,
     pub total_money_deposited: u64,
     pub total_money_withdrawn: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub current_streak: u64, // challenges completed in a row
+    pub best_streak: u64,
+    pub reputation: u64,
+    pub bump: u8,
+}
+
+impl UserAccount {
+    // the matching entry of the user name registry is claimed by the caller
+    pub fn set_user_name(&mut self, user_name: String) -> Result<()> {
+        if user_name.is_empty() || user_name.len() > MAX_USER_NAME_LEN {
+            return Err(ErrorCode::InvalidUserName.into());
+        }
+        self.user_name = user_name;
+        Ok(())
+    }
+
+    // shared by the registration and the profile updates
+    pub fn set_profile(&mut self, avatar_uri: String, bio_hash: [u8; 32]) -> Result<()> {
+        if avatar_uri.len() > MAX_AVATAR_URI_LEN {
+            return Err(ErrorCode::AvatarUriTooLong.into());
+        }
+        self.avatar_uri = avatar_uri;
+        self.bio_hash = bio_hash;
+        Ok(())
+    }
+
+    // wins and losses are recorded when the participant is settled
+    pub fn record_win(&mut self) {
+        self.wins += 1;
+        self.current_streak += 1;
+        self.best_streak = self.best_streak.max(self.current_streak);
+        self.reputation += REPUTATION_PER_WIN;
+    }
+
+    pub fn record_loss(&mut self) {
+        self.losses += 1;
+        self.current_streak = 0;
+        self.reputation = self.reputation.saturating_sub(REPUTATION_PER_LOSS);
+    }
+}
+
+// entry of the user name registry, resolves a name to the wallet owning it
+#[account]
+#[derive(InitSpace)]
+pub struct UserNameAccount {
+    pub user_address: Pubkey,
+    pub rent_payer: Pubkey, // gets the rent back when the name is released
     pub bump: u8,
 }
 
@@ -20,10 +81,144 @@ pub struct UserChallengeAccount {
     pub description: String,
     pub user_address: Pubkey,
     pub is_joined: bool,
+    pub participant_index: u64,
     pub money_deposited: u64,
+    pub reward: u64, // share of the forfeited pool, set when the winner claims
+    pub refund: u64, // part of the deposit given back when the challenge is not completed
+    pub has_forfeited: bool,
+    pub rent_payer: Pubkey, // gets the rent back when the account is closed
     pub is_challenge_completed: bool,
     pub bump: u8,
     pub score: u64,
-    pub vote_in_positive: u64,
-    pub vote_in_negative: u64,
+    pub is_proof_submitted: bool,
+    pub proof_hash: [u8; 32],
+    #[max_len(MAX_PROOF_URI_LEN)]
+    pub proof_uri: String,
+    pub dispute_status: DisputeStatus,
+}
+
+impl UserChallengeAccount {
+    // the vote tally is given for vote verified challenges
+    pub fn has_completed(
+        &self,
+        requires_proof: bool,
+        vote_tally: Option<&VoteTallyAccount>,
+    ) -> bool {
+        // participants who quit can't win, whatever their verification says
+        if self.has_forfeited {
+            return false;
+        }
+        if self.is_challenge_completed {
+            return true;
+        }
+        // nobody can vote for a participant without a proof, so no votes must not approve them
+        if requires_proof && !self.is_proof_submitted {
+            return false;
+        }
+        vote_tally.is_some_and(|vote_tally| vote_tally.is_approved(self.participant_index))
+    }
+
+    // once the challenge is finalized, whether something is left to claim
+    pub fn has_payout(&self) -> bool {
+        self.money_deposited > 0 && (self.is_challenge_completed || self.refund > 0)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DisputeStatus {
+    None,
+    Open,
+    Upheld,
+    Rejected,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_account() -> UserAccount {
+        UserAccount {
+            user_name: String::new(),
+            avatar_uri: String::new(),
+            bio_hash: [0; 32],
+            user_address: Pubkey::default(),
+            total_participations: 0,
+            total_money_deposited: 0,
+            total_money_withdrawn: 0,
+            wins: 0,
+            losses: 0,
+            current_streak: 0,
+            best_streak: 0,
+            reputation: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn a_loss_resets_the_streak_but_keeps_the_best_one() {
+        let mut user_account = user_account();
+        user_account.record_win();
+        user_account.record_win();
+        user_account.record_loss();
+        user_account.record_win();
+        assert_eq!(user_account.wins, 3);
+        assert_eq!(user_account.losses, 1);
+        assert_eq!(user_account.current_streak, 1);
+        assert_eq!(user_account.best_streak, 2);
+        assert_eq!(
+            user_account.reputation,
+            3 * REPUTATION_PER_WIN - REPUTATION_PER_LOSS
+        );
+    }
+
+    #[test]
+    fn reputation_never_goes_below_zero() {
+        let mut user_account = user_account();
+        user_account.record_loss();
+        user_account.record_loss();
+        assert_eq!(user_account.losses, 2);
+        assert_eq!(user_account.reputation, 0);
+
+        user_account.record_win();
+        assert_eq!(user_account.reputation, REPUTATION_PER_WIN);
+    }
+
+    fn user_challenge_account() -> UserChallengeAccount {
+        UserChallengeAccount {
+            challenge_address: Pubkey::default(),
+            description: String::new(),
+            user_address: Pubkey::default(),
+            is_joined: true,
+            participant_index: 0,
+            money_deposited: 1_000,
+            reward: 0,
+            refund: 0,
+            has_forfeited: false,
+            rent_payer: Pubkey::default(),
+            is_challenge_completed: false,
+            bump: 0,
+            score: 0,
+            is_proof_submitted: false,
+            proof_hash: [0; 32],
+            proof_uri: String::new(),
+            dispute_status: DisputeStatus::None,
+        }
+    }
+
+    #[test]
+    fn skipping_the_proof_fails_the_challenge() {
+        // nobody could vote for the participant, the empty tally must not approve them
+        let vote_tally: VoteTallyAccount = bytemuck::Zeroable::zeroed();
+        let mut user_challenge_account = user_challenge_account();
+        assert!(!user_challenge_account.has_completed(true, Some(&vote_tally)));
+        assert!(user_challenge_account.has_completed(false, Some(&vote_tally)));
+
+        user_challenge_account.is_proof_submitted = true;
+        assert!(user_challenge_account.has_completed(true, Some(&vote_tally)));
+
+        // an upheld dispute still decides the outcome
+        user_challenge_account.is_proof_submitted = false;
+        user_challenge_account.is_challenge_completed = true;
+        assert!(user_challenge_account.has_completed(true, Some(&vote_tally)));
+    }
 }
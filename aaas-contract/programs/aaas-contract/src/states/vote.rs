@@ -1,12 +1,128 @@
 use anchor_lang::prelude::*;
 
-#[account]
-#[derive(InitSpace)]
-pub struct VoteAccount {
+use crate::{
+    constants::{MAX_VOTE_PARTICIPANTS, VOTE_BITMAP_WORDS},
+    errors::ErrorCode,
+};
+
+// one tally per vote verified challenge, only participants vote, so voters and the
+// participants they vote for are both addressed by their participant index
+#[account(zero_copy)]
+pub struct VoteTallyAccount {
     pub challenge_address: Pubkey,
+    // voted[voter] has the bit of every participant the voter has voted for
+    pub voted: [[u64; VOTE_BITMAP_WORDS]; MAX_VOTE_PARTICIPANTS],
+    // approvals[voter] has the bit of every participant the voter has voted as completed
+    pub approvals: [[u64; VOTE_BITMAP_WORDS]; MAX_VOTE_PARTICIPANTS],
+    pub vote_in_positive: [u16; MAX_VOTE_PARTICIPANTS],
+    pub vote_in_negative: [u16; MAX_VOTE_PARTICIPANTS],
+    pub vote_revisions: [u16; MAX_VOTE_PARTICIPANTS], // number of times each voter has changed a vote
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+impl VoteTallyAccount {
+    pub fn cast_vote(&mut self, voter: u64, participant: u64, is_completed: bool) -> Result<()> {
+        let (voter, participant) = (voter as usize, participant as usize);
+        let (word, bit) = (participant / 64, 1u64 << (participant % 64));
+
+        // a vote can be changed until the verification is over, but not cast twice
+        if self.voted[voter][word] & bit != 0 {
+            let was_completed = self.approvals[voter][word] & bit != 0;
+            if was_completed == is_completed {
+                return Err(ErrorCode::UserHasAlreadyVoted.into());
+            }
+            // take back the previous vote before counting the new one
+            if was_completed {
+                self.vote_in_positive[participant] -= 1;
+            } else {
+                self.vote_in_negative[participant] -= 1;
+            }
+            self.vote_revisions[voter] += 1;
+        }
+
+        if is_completed {
+            self.vote_in_positive[participant] += 1;
+            self.approvals[voter][word] |= bit;
+        } else {
+            self.vote_in_negative[participant] += 1;
+            self.approvals[voter][word] &= !bit;
+        }
+        self.voted[voter][word] |= bit;
+        Ok(())
+    }
+
+    // the participant wins when positive votes >= negative votes
+    pub fn is_approved(&self, participant: u64) -> bool {
+        let participant = participant as usize;
+        self.vote_in_positive[participant] >= self.vote_in_negative[participant]
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct VoteEntry {
     pub user_address: Pubkey,
-    pub is_voted: bool,
     pub is_completed: bool,
-    pub bump: u8,
-    pub voter_address: Pubkey,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vote_tally() -> VoteTallyAccount {
+        bytemuck::Zeroable::zeroed()
+    }
+
+    #[test]
+    fn flipping_a_vote_moves_it_between_the_counts() {
+        let mut vote_tally = vote_tally();
+        vote_tally.cast_vote(0, 1, true).unwrap();
+        assert_eq!(vote_tally.vote_in_positive[1], 1);
+        assert_eq!(vote_tally.vote_in_negative[1], 0);
+
+        vote_tally.cast_vote(0, 1, false).unwrap();
+        assert_eq!(vote_tally.vote_in_positive[1], 0);
+        assert_eq!(vote_tally.vote_in_negative[1], 1);
+        assert!(!vote_tally.is_approved(1));
+    }
+
+    #[test]
+    fn repeating_a_vote_fails() {
+        let mut vote_tally = vote_tally();
+        vote_tally.cast_vote(0, 1, true).unwrap();
+        assert_eq!(
+            vote_tally.cast_vote(0, 1, true).unwrap_err(),
+            ErrorCode::UserHasAlreadyVoted.into()
+        );
+        assert_eq!(vote_tally.vote_in_positive[1], 1);
+    }
+
+    #[test]
+    fn every_change_is_a_revision() {
+        let mut vote_tally = vote_tally();
+        vote_tally.cast_vote(0, 1, true).unwrap();
+        assert_eq!(vote_tally.vote_revisions[0], 0);
+
+        vote_tally.cast_vote(0, 1, false).unwrap();
+        vote_tally.cast_vote(0, 1, true).unwrap();
+        assert_eq!(vote_tally.vote_revisions[0], 2);
+        // votes of another voter are not revisions
+        vote_tally.cast_vote(2, 1, true).unwrap();
+        assert_eq!(vote_tally.vote_revisions[0], 2);
+        assert_eq!(vote_tally.vote_in_positive[1], 2);
+    }
+
+    #[test]
+    fn votes_reach_every_participant() {
+        let mut vote_tally = vote_tally();
+        let last = MAX_VOTE_PARTICIPANTS as u64 - 1;
+        vote_tally.cast_vote(last, 64, false).unwrap();
+        vote_tally.cast_vote(0, last, true).unwrap();
+        assert!(!vote_tally.is_approved(64));
+        assert!(vote_tally.is_approved(last));
+        // the bits of different words don't mix
+        assert!(vote_tally.is_approved(0));
+        vote_tally.cast_vote(last, 0, true).unwrap();
+        assert_eq!(vote_tally.vote_in_positive[0], 1);
+    }
 }
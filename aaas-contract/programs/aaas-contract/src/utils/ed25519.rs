@@ -0,0 +1,58 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+// the invite is signed off-chain by the challenge creator over challenge_id || invitee || expiry
+pub fn invite_message(challenge_id: u64, invitee: &Pubkey, expiry: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(48);
+    message.extend_from_slice(&challenge_id.to_le_bytes());
+    message.extend_from_slice(invitee.as_ref());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+// the signature itself is checked by the ed25519 program, in the instruction right before this one,
+// here we only check that it was made by the expected signer over the expected message
+pub fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<bool> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Ok(false);
+    }
+    let instruction = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    if instruction.program_id != ed25519_program::ID || !instruction.accounts.is_empty() {
+        return Ok(false);
+    }
+
+    // data is laid out as [num_signatures: u8, padding: u8, offsets: 7 x u16, ...]
+    let data = &instruction.data;
+    if data.len() < 16 || data[0] != 1 {
+        return Ok(false);
+    }
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let signature_instruction_index = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_instruction_index = read_u16(8);
+    let message_data_offset = read_u16(10) as usize;
+    let message_data_size = read_u16(12) as usize;
+    let message_instruction_index = read_u16(14);
+
+    // everything has to be in the ed25519 instruction itself
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX
+    {
+        return Ok(false);
+    }
+
+    let signed_public_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = data.get(message_data_offset..message_data_offset + message_data_size);
+    Ok(signed_public_key == Some(signer.as_ref()) && signed_message == Some(message))
+}
@@ -0,0 +1,19 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+// leaves and nodes are hashed with different prefixes, so a node can't be passed off as a leaf
+pub fn merkle_leaf(address: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0u8], address.as_ref()]).to_bytes()
+}
+
+// sibling pairs are sorted before hashing, so the proof doesn't need the position of each node
+pub fn verify_merkle_proof(root: &[u8; 32], address: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let mut node = merkle_leaf(address);
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&[1u8], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[1u8], sibling, &node]).to_bytes()
+        };
+    }
+    node == *root
+}
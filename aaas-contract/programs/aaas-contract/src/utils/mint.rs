@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        state::Mint as MintState,
+    },
+    token_interface::Mint,
+};
+
+use crate::errors::ErrorCode;
+
+// extensions letting someone else move or lock the tokens held by the treasury,
+// or needing accounts the program doesn't pass along
+const UNSUPPORTED_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::NonTransferable,
+    ExtensionType::TransferHook,
+];
+
+pub fn validate_mint(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    if mint.to_account_info().owner != &anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_with_extensions = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    if mint_with_extensions
+        .get_extension_types()?
+        .iter()
+        .any(|extension_type| UNSUPPORTED_EXTENSIONS.contains(extension_type))
+    {
+        return Err(ErrorCode::UnsupportedMint.into());
+    }
+    Ok(())
+}
+
+// the amount withheld by the token program when transferring from a mint with a transfer fee
+pub fn transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    if mint.to_account_info().owner != &anchor_spl::token_2022::ID {
+        return Ok(0);
+    }
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_with_extensions = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let Ok(transfer_fee_config) = mint_with_extensions.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    let fee = transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(ErrorCode::UnsupportedMint)?;
+    Ok(fee)
+}
@@ -0,0 +1,17 @@
+pub mod merkle;
+pub use merkle::*;
+
+pub mod ed25519;
+pub use ed25519::*;
+
+pub mod treasury;
+pub use treasury::*;
+
+pub mod mint;
+pub use mint::*;
+
+pub mod user_name;
+pub use user_name::*;
+
+pub mod payout;
+pub use payout::*;
@@ -0,0 +1,181 @@
+use crate::{constants::MAX_BPS, states::RankedWinner};
+
+// the forfeited pool once the fees are taken, fees are never taken from the deposits of the winners
+pub struct PoolSplit {
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub distributable_pool: u64, // left for the winners, or the beneficiary of charity challenges
+}
+
+pub fn bps_share(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / MAX_BPS as u128) as u64
+}
+
+pub fn split_forfeited_pool(
+    forfeited_pool: u64,
+    platform_fee_bps: u16,
+    creator_fee_bps: u16,
+) -> PoolSplit {
+    let platform_fee = bps_share(forfeited_pool, platform_fee_bps);
+    let creator_fee = bps_share(forfeited_pool, creator_fee_bps);
+    PoolSplit {
+        platform_fee,
+        creator_fee,
+        distributable_pool: forfeited_pool - platform_fee - creator_fee,
+    }
+}
+
+// shares are rounded down, so the shares of all the winners never exceed the pool
+pub fn stake_weighted_share(pool: u64, stake: u64, total_stake: u64) -> u64 {
+    (pool as u128 * stake as u128)
+        .checked_div(total_stake as u128)
+        .unwrap_or(0) as u64
+}
+
+// the shares of the ranks nobody reached are spread over the paid ranks
+pub fn ranked_share(pool: u64, payout_distribution: &[u16], paid_ranks: usize, rank: usize) -> u64 {
+    let paid_ranks = paid_ranks.min(payout_distribution.len());
+    if rank >= paid_ranks {
+        return 0;
+    }
+    let total_share: u128 = payout_distribution[..paid_ranks]
+        .iter()
+        .map(|&share| share as u128)
+        .sum();
+    (pool as u128 * payout_distribution[rank] as u128)
+        .checked_div(total_share)
+        .unwrap_or(0) as u64
+}
+
+// part of the deposit earned by a participant who fell short of the target
+pub fn proportional_refund(money_deposited: u64, score: u64, target: u64) -> u64 {
+    (money_deposited as u128 * score.min(target) as u128)
+        .checked_div(target as u128)
+        .unwrap_or(0) as u64
+}
+
+// keeps the top scorers sorted by score, participants are settled in the order they joined
+// so the earlier participants win the ties
+pub fn rank_winner(
+    ranked_winners: &mut Vec<RankedWinner>,
+    winner: RankedWinner,
+    paid_ranks: usize,
+) {
+    let rank = ranked_winners
+        .iter()
+        .position(|ranked_winner| ranked_winner.score < winner.score)
+        .unwrap_or(ranked_winners.len());
+    if rank < paid_ranks {
+        ranked_winners.insert(rank, winner);
+        ranked_winners.truncate(paid_ranks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fees_are_only_taken_from_the_forfeited_pool() {
+        let split = split_forfeited_pool(1_000, 500, 250);
+        assert_eq!(split.platform_fee, 50);
+        assert_eq!(split.creator_fee, 25);
+        assert_eq!(split.distributable_pool, 925);
+
+        // nothing forfeited, nothing to take a fee from
+        let split = split_forfeited_pool(0, 500, 250);
+        assert_eq!(split.platform_fee + split.creator_fee, 0);
+        assert_eq!(split.distributable_pool, 0);
+    }
+
+    #[test]
+    fn rounding_dust_never_exceeds_the_treasury() {
+        // winners with awkward stakes, losers forfeiting everything
+        let winner_stakes = [333u64, 1_001, 7, 250_000_003];
+        let loser_stakes = [999_999u64, 13, 77_777];
+        let treasury: u64 = winner_stakes.iter().chain(loser_stakes.iter()).sum();
+        let winners_stake: u64 = winner_stakes.iter().sum();
+        let forfeited_pool: u64 = loser_stakes.iter().sum();
+
+        let split = split_forfeited_pool(forfeited_pool, 333, 777);
+        let payouts: u64 = winner_stakes
+            .iter()
+            .map(|&stake| {
+                stake + stake_weighted_share(split.distributable_pool, stake, winners_stake)
+            })
+            .sum();
+        assert!(payouts + split.platform_fee + split.creator_fee <= treasury);
+
+        // same for the ranked payouts
+        let payout_distribution = [3_333, 3_333, 3_334];
+        let ranked_payouts: u64 = (0..payout_distribution.len())
+            .map(|rank| {
+                ranked_share(
+                    split.distributable_pool,
+                    &payout_distribution,
+                    payout_distribution.len(),
+                    rank,
+                )
+            })
+            .sum();
+        assert!(ranked_payouts <= split.distributable_pool);
+    }
+
+    #[test]
+    fn ranked_shares_are_renormalized_over_the_paid_ranks() {
+        let payout_distribution = [5_000, 3_000, 2_000];
+        // every rank is paid
+        assert_eq!(ranked_share(1_000, &payout_distribution, 3, 0), 500);
+        assert_eq!(ranked_share(1_000, &payout_distribution, 3, 2), 200);
+
+        // two winners for three ranks, the third share is spread over the first two
+        assert_eq!(ranked_share(1_000, &payout_distribution, 2, 0), 625);
+        assert_eq!(ranked_share(1_000, &payout_distribution, 2, 1), 375);
+        assert_eq!(ranked_share(1_000, &payout_distribution, 2, 2), 0);
+
+        // a single winner takes the whole pool
+        assert_eq!(ranked_share(1_000, &payout_distribution, 1, 0), 1_000);
+    }
+
+    #[test]
+    fn top_scorers_are_ranked_and_earlier_participants_win_the_ties() {
+        let mut ranked_winners = Vec::new();
+        for (participant_index, score) in [(0, 10), (1, 30), (2, 20), (3, 30), (4, 5)] {
+            rank_winner(
+                &mut ranked_winners,
+                RankedWinner {
+                    participant_index,
+                    score,
+                },
+                3,
+            );
+        }
+        let ranking: Vec<u64> = ranked_winners
+            .iter()
+            .map(|ranked_winner| ranked_winner.participant_index)
+            .collect();
+        assert_eq!(ranking, vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn short_scorers_get_back_their_deposit_in_proportion_to_their_score() {
+        // an 80% scorer gets 80% of the deposit back
+        assert_eq!(proportional_refund(1_000, 8_000, 10_000), 800);
+        assert_eq!(proportional_refund(1_000, 0, 10_000), 0);
+        // the refund is capped at the deposit
+        assert_eq!(proportional_refund(1_000, 20_000, 10_000), 1_000);
+        // no target, nothing to measure the score against
+        assert_eq!(proportional_refund(1_000, 8_000, 0), 0);
+    }
+
+    #[test]
+    fn winnings_are_weighted_by_stake() {
+        // a winner staking three times more takes three times the share
+        assert_eq!(stake_weighted_share(1_000, 100, 400), 250);
+        assert_eq!(stake_weighted_share(1_000, 300, 400), 750);
+        // equal stakes split the pool equally, the dust stays in the treasury
+        assert_eq!(stake_weighted_share(1_000, 100, 300), 333);
+        // no winners, nothing to share
+        assert_eq!(stake_weighted_share(1_000, 0, 0), 0);
+    }
+}
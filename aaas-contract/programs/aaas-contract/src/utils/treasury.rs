@@ -0,0 +1,165 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{errors::ErrorCode, states::ChallengeAccount, utils::transfer_fee};
+
+// the account holding the stake of a wallet, the wallet itself for native challenges
+pub fn stake_account<'info>(
+    challenge_account: &ChallengeAccount,
+    wallet: &AccountInfo<'info>,
+    token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<AccountInfo<'info>> {
+    if challenge_account.is_native {
+        return Ok(wallet.clone());
+    }
+    token_account
+        .map(|token_account| token_account.to_account_info())
+        .ok_or(ErrorCode::MissingTokenAccounts.into())
+}
+
+// deposits are lamport transfers for native challenges, token transfers otherwise,
+// returns the amount received by the treasury after the transfer fee of the mint
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_to_treasury<'info>(
+    challenge_account: &ChallengeAccount,
+    treasury_account: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &Signer<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<u64> {
+    if challenge_account.is_native {
+        let transfer_accounts_option = Transfer {
+            from: from.clone(),
+            to: treasury_account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), transfer_accounts_option);
+        transfer(cpi_ctx, amount)?;
+        return Ok(amount);
+    }
+
+    let (Some(mint), Some(token_program)) = (mint, token_program) else {
+        return Err(ErrorCode::MissingTokenAccounts.into());
+    };
+    let transfer_accounts_option = TransferChecked {
+        from: from.clone(),
+        to: treasury_account.clone(),
+        mint: mint.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), transfer_accounts_option);
+    transfer_checked(cpi_ctx, amount, mint.decimals)?;
+    Ok(amount - transfer_fee(mint, amount)?)
+}
+
+// the treasury is its own authority, so every payout is signed with the treasury seeds
+pub fn transfer_from_treasury<'info>(
+    challenge_account: &ChallengeAccount,
+    treasury_account: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let signers_seeds: &[&[&[u8]]] = &[&[
+        b"treasury_account",
+        &challenge_account.challenge_id.to_le_bytes(),
+        &[challenge_account.treasury_bump],
+    ]];
+
+    if challenge_account.is_native {
+        let transfer_accounts_option = Transfer {
+            from: treasury_account.clone(),
+            to: to.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), transfer_accounts_option)
+            .with_signer(signers_seeds);
+        return transfer(cpi_ctx, amount);
+    }
+
+    let (Some(mint), Some(token_program)) = (mint, token_program) else {
+        return Err(ErrorCode::MissingTokenAccounts.into());
+    };
+    let transfer_accounts_option = TransferChecked {
+        from: treasury_account.clone(),
+        to: to.clone(),
+        mint: mint.to_account_info(),
+        authority: treasury_account.clone(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), transfer_accounts_option)
+        .with_signer(signers_seeds);
+    transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+// moves what is left in the treasury to `to` and closes it, the rent goes back to `rent_destination`
+pub fn close_treasury<'info>(
+    challenge_account: &ChallengeAccount,
+    treasury_account: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    rent_destination: &AccountInfo<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    if challenge_account.is_native {
+        // a system account is closed once all its lamports are moved out
+        let rent_exemption = Rent::get()?.minimum_balance(0);
+        let remaining_amount = treasury_account.lamports().saturating_sub(rent_exemption);
+        let rent = treasury_account.lamports() - remaining_amount;
+        for (destination, amount) in [(to, remaining_amount), (rent_destination, rent)] {
+            if amount > 0 {
+                transfer_from_treasury(
+                    challenge_account,
+                    treasury_account,
+                    destination,
+                    mint,
+                    token_program,
+                    system_program,
+                    amount,
+                )?;
+            }
+        }
+        return Ok(remaining_amount);
+    }
+
+    let Some(token_program) = token_program else {
+        return Err(ErrorCode::MissingTokenAccounts.into());
+    };
+    let remaining_amount =
+        TokenAccount::try_deserialize(&mut &treasury_account.try_borrow_data()?[..])?.amount;
+    if remaining_amount > 0 {
+        transfer_from_treasury(
+            challenge_account,
+            treasury_account,
+            to,
+            mint,
+            Some(token_program),
+            system_program,
+            remaining_amount,
+        )?;
+    }
+
+    let signers_seeds: &[&[&[u8]]] = &[&[
+        b"treasury_account",
+        &challenge_account.challenge_id.to_le_bytes(),
+        &[challenge_account.treasury_bump],
+    ]];
+    let close_accounts_option = CloseAccount {
+        account: treasury_account.clone(),
+        destination: rent_destination.clone(),
+        authority: treasury_account.clone(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), close_accounts_option)
+        .with_signer(signers_seeds);
+    close_account(cpi_ctx)?;
+    Ok(remaining_amount)
+}
@@ -0,0 +1,7 @@
+use anchor_lang::solana_program::hash::hash;
+
+// user names are unique regardless of their case, the registry is keyed by the hash of the
+// lowercase name so that any name fits in a seed, clients derive it as sha256(lowercase(name))
+pub fn user_name_seed(user_name: &str) -> [u8; 32] {
+    hash(user_name.to_lowercase().as_bytes()).to_bytes()
+}
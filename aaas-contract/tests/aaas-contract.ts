@@ -7,15 +7,25 @@ import {
   SystemProgram,
   LAMPORTS_PER_SOL,
   Transaction,
+  Ed25519Program,
+  sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
   createMint,
   createAccount,
   mintTo,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
 import { BN } from "bn.js";
+import { createHash } from "crypto";
 
 describe("aaas-contract", () => {
   // Configure the client to use the local cluster.
@@ -37,6 +47,18 @@ describe("aaas-contract", () => {
   const startTime = new BN(Math.floor(Date.now() / 1000) + 60); // 60 seconds in the future
   const endTime = new BN(Math.floor(Date.now() / 1000) + 300); // 5 minutes in the future
   const moneyPerParticipant = new BN(1000000000); // 1 token with 9 decimals
+  const defaultRules = {
+    requiresProof: false,
+    jurySize: 0,
+    allowlistRoot: null,
+    creatorFeeBps: 0,
+    payoutDistribution: [],
+    isProportional: false,
+    maxStake: new BN(0),
+    forfeitRefundBps: 0,
+    inviteOnly: false,
+    claimDuration: new BN(7 * 24 * 60 * 60), // a week to claim once the disputes are over
+  };
 
   // Token accounts
   let mint: PublicKey;
@@ -58,6 +80,29 @@ describe("aaas-contract", () => {
   let user2ChallengeAccount: PublicKey;
   let user2ChallengeBump: number;
 
+  // vote verified challenges keep their votes in a vote tally account
+  const findVoteTallyAccount = (id: BN) => {
+    const [challenge] = PublicKey.findProgramAddressSync(
+      [Buffer.from("challenge_account"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [voteTally] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vote_tally_account"), challenge.toBytes()],
+      program.programId
+    );
+    return voteTally;
+  };
+
+  // the user name registry is keyed by the hash of the lowercase name
+  const findUserNameAccount = (userName: string) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_name"),
+        createHash("sha256").update(userName.toLowerCase()).digest(),
+      ],
+      program.programId
+    );
+
   before(async () => {
     // Airdrop SOL to the payer
     const airdropSignature = await provider.connection.requestAirdrop(
@@ -223,7 +268,9 @@ describe("aaas-contract", () => {
         endTime,
         moneyPerParticipant,
         false, // not private
-        [] // no private group
+        [], // no private group
+        defaultRules,
+        false
       )
       .accounts({
         signer: payer.publicKey,
@@ -251,12 +298,175 @@ describe("aaas-contract", () => {
     assert.equal(challengeData.isPrivate, false);
   });
 
+  it("Register users", async () => {
+    try {
+      await program.methods
+        .registerUser("x".repeat(33), "", Array(32).fill(0))
+        .accounts({
+          signer: user1.publicKey,
+          payer: user1.publicKey,
+          userNameAccount: findUserNameAccount("x".repeat(33))[0],
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because the name is too long");
+    } catch (error) {
+      assert.include(error.message, "InvalidUserName");
+    }
+
+    await program.methods
+      .registerUser("Jaikumar", "", Array(32).fill(0))
+      .accounts({
+        signer: user1.publicKey,
+        payer: user1.publicKey,
+        userNameAccount: findUserNameAccount("Jaikumar")[0],
+      })
+      .signers([user1])
+      .rpc();
+
+    // the payer can sponsor the registration of a user
+    await program.methods
+      .registerUser("User Two", "", Array(32).fill(0))
+      .accounts({
+        signer: user2.publicKey,
+        payer: payer.publicKey,
+        userNameAccount: findUserNameAccount("User Two")[0],
+      })
+      .signers([user2, payer])
+      .rpc();
+
+    // names are unique regardless of their case
+    try {
+      await program.methods
+        .renameUser("user two")
+        .accounts({
+          signer: user1.publicKey,
+          payer: user1.publicKey,
+          oldUserNameAccount: findUserNameAccount("Jaikumar")[0],
+          rentPayer: user1.publicKey,
+          newUserNameAccount: findUserNameAccount("user two")[0],
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because the name is taken");
+    } catch (error) {
+      assert.include(error.message, "already in use");
+    }
+
+    // the payer sponsors the new name, the rent of the previous one goes back to user1
+    const rentPayerBalanceBefore = await provider.connection.getBalance(
+      user1.publicKey
+    );
+    const [previousUserNameAccount] = findUserNameAccount("Jaikumar");
+    const previousUserNameRent = (
+      await provider.connection.getAccountInfo(previousUserNameAccount)
+    ).lamports;
+    await program.methods
+      .renameUser("Jaikumar Mohite")
+      .accounts({
+        signer: user1.publicKey,
+        payer: payer.publicKey,
+        oldUserNameAccount: previousUserNameAccount,
+        rentPayer: user1.publicKey,
+        newUserNameAccount: findUserNameAccount("Jaikumar Mohite")[0],
+      })
+      .signers([user1, payer])
+      .rpc();
+
+    // the registry resolves the name to the wallet, the previous name is released
+    const [userNameAccount] = findUserNameAccount("JAIKUMAR MOHITE");
+    const userNameData = await program.account.userNameAccount.fetch(
+      userNameAccount
+    );
+    assert.equal(
+      userNameData.userAddress.toString(),
+      user1.publicKey.toString()
+    );
+    assert.equal(userNameData.rentPayer.toString(), payer.publicKey.toString());
+    assert.isNull(
+      await provider.connection.getAccountInfo(previousUserNameAccount)
+    );
+    const rentPayerBalanceAfter = await provider.connection.getBalance(
+      user1.publicKey
+    );
+    assert.equal(
+      rentPayerBalanceAfter - rentPayerBalanceBefore,
+      previousUserNameRent
+    );
+
+    // a change of case keeps the entry of the registry
+    for (const casedUserName of ["jaikumar mohite", "Jaikumar Mohite"]) {
+      await program.methods
+        .renameUser(casedUserName)
+        .accounts({
+          signer: user1.publicKey,
+          payer: user1.publicKey,
+          oldUserNameAccount: userNameAccount,
+          rentPayer: payer.publicKey,
+          newUserNameAccount: null,
+        })
+        .signers([user1])
+        .rpc();
+
+      const userData = await program.account.userAccount.fetch(user1Account);
+      assert.equal(userData.userName, casedUserName);
+      assert.isNotNull(await provider.connection.getAccountInfo(userNameAccount));
+    }
+
+    await program.methods
+      .updateProfile("https://example.com/avatar.png", Array(32).fill(1))
+      .accounts({
+        signer: user1.publicKey,
+      })
+      .signers([user1])
+      .rpc();
+
+    const userData = await program.account.userAccount.fetch(user1Account);
+    assert.equal(userData.userName, "Jaikumar Mohite");
+    assert.equal(userData.avatarUri, "https://example.com/avatar.png");
+    assert.equal(userData.userAddress.toString(), user1.publicKey.toString());
+  });
+
   it("Join a challenge", async () => {
+    // the stake is fixed when the challenge has no max stake
+    try {
+      await program.methods
+        .joinChallenge(
+          challengeId,
+          "I will try my best",
+          [],
+          null,
+          moneyPerParticipant.muln(2)
+        )
+        .accounts({
+          signer: user1.publicKey,
+          payer: user1.publicKey,
+          mint,
+          treasuryAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because the stake is out of range");
+    } catch (error) {
+      assert.include(error.message, "InvalidStake");
+    }
+
     // User1 joins the challenge
     const tx = await program.methods
-      .joinChallenge(challengeId, "Jaikumar Mohite", "I will try my best")
+      .joinChallenge(
+        challengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
       .accounts({
         signer: user1.publicKey,
+        payer: user1.publicKey,
         mint,
         treasuryAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -280,6 +490,8 @@ describe("aaas-contract", () => {
     const userData = await program.account.userAccount.fetch(user1Account);
     assert.equal(userData.userName, "Jaikumar Mohite");
     assert.equal(userData.totalParticipations.toString(), "1");
+    assert.equal(userData.wins.toString(), "0");
+    assert.equal(userData.reputation.toString(), "0");
     assert.equal(
       userData.totalMoneyDeposited.toString(),
       moneyPerParticipant.toString()
@@ -297,6 +509,121 @@ describe("aaas-contract", () => {
     assert.equal(userChallengeData.isChallengeCompleted, false);
   });
 
+  it("Update a challenge before it starts", async () => {
+    // the creator can rename the challenge and extend it
+    const extendedEndTime = endTime.add(new BN(60));
+    await program.methods
+      .updateChallenge(
+        challengeId,
+        "Lets fit together",
+        null,
+        [],
+        [],
+        extendedEndTime,
+        null,
+        null
+      )
+      .accounts({
+        signer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    const challengeData = await program.account.challengeAccount.fetch(
+      challengeAccount
+    );
+    assert.equal(
+      challengeData.challengeInformation.challengeName,
+      "Lets fit together"
+    );
+    assert.equal(challengeData.endTime.toString(), extendedEndTime.toString());
+
+    // the stake can't change once someone has joined
+    try {
+      await program.methods
+        .updateChallenge(
+          challengeId,
+          null,
+          null,
+          [],
+          [],
+          null,
+          moneyPerParticipant.mul(new BN(2)),
+          null
+        )
+        .accounts({
+          signer: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed because user1 has joined the challenge");
+    } catch (error) {
+      assert.include(error.message, "ChallengeHasParticipants");
+    }
+
+    // name and description must fit in the challenge account
+    try {
+      await program.methods
+        .updateChallenge(
+          challengeId,
+          "x".repeat(33),
+          null,
+          [],
+          [],
+          null,
+          null,
+          null
+        )
+        .accounts({
+          signer: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed because the name is too long");
+    } catch (error) {
+      assert.include(error.message, "InvalidChallengeName");
+    }
+    try {
+      await program.methods
+        .updateChallenge(
+          challengeId,
+          null,
+          "x".repeat(257),
+          [],
+          [],
+          null,
+          null,
+          null
+        )
+        .accounts({
+          signer: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed because the description is too long");
+    } catch (error) {
+      assert.include(error.message, "ChallengeDescriptionTooLong");
+    }
+
+    // only the creator can update the challenge
+    try {
+      await program.methods
+        .updateChallenge(challengeId, "Mine", null, [], [], null, null, null)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because user1 is not the creator");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorizedCreator");
+    }
+  });
+
   it("Update challenge status for user", async () => {
     try {
       await program.methods
@@ -334,10 +661,13 @@ describe("aaas-contract", () => {
         endTime,
         moneyPerParticipant,
         false,
-        []
+        [],
+        defaultRules,
+        false
       )
       .accounts({
         signer: payer.publicKey,
+        voteTallyAccount: findVoteTallyAccount(voteBasedChallengeId),
         mint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -351,6 +681,8 @@ describe("aaas-contract", () => {
         })
         .accounts({
           signer: user2.publicKey,
+          voter: user2.publicKey,
+          sessionKeyAccount: null,
         })
         .signers([user2])
         .rpc();
@@ -361,6 +693,26 @@ describe("aaas-contract", () => {
     }
   });
 
+  it("Forfeit a challenge", async () => {
+    // participants can only quit once the challenge is running
+    try {
+      await program.methods
+        .forfeitChallenge(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+          mint,
+          treasuryAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not started");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotStarted");
+    }
+  });
+
   it("Claim challenge rewards", async () => {
     try {
       await program.methods
@@ -380,53 +732,623 @@ describe("aaas-contract", () => {
     }
   });
 
-  // Add test for private challenge initialization and joining
-  it("Initialize and join a private challenge", async () => {
-    // Initialize a private challenge
-    const privateGroupChallengeId = new BN(4);
+  it("Finalize a challenge and collect the fees", async () => {
+    // the outcomes can't be settled before the verification is over
+    try {
+      await program.methods
+        .settleParticipants(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+          voteTallyAccount: null,
+        })
+        .remainingAccounts([
+          { pubkey: user1ChallengeAccount, isSigner: false, isWritable: true },
+          { pubkey: user1Account, isSigner: false, isWritable: true },
+        ])
+        .signers([user1])
+        .rpc();
 
-    // Define private group with only user1 allowed
-    const privateGroup = [user1.publicKey];
+      assert.fail("Should have failed because challenge is under verification");
+    } catch (error) {
+      assert.include(error.message, "ChallengeUnderVerification");
+    }
+    try {
+      await program.methods
+        .finalizeChallenge(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
 
-    // Initialize the private challenge
-    const initTx = await program.methods
-      .initializeChallenge(
-        privateGroupChallengeId,
-        { voteBased: {} },
-        "Lets complete my homework",
-        "I will do homework for 10 hours",
-        startTime,
-        endTime,
-        moneyPerParticipant,
-        true, // private challenge
-        privateGroup // only user1 is allowed
-      )
-      .accounts({
-        signer: payer.publicKey,
-        mint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([payer])
-      .rpc();
+      assert.fail("Should have failed because challenge is under verification");
+    } catch (error) {
+      assert.include(error.message, "ChallengeUnderVerification");
+    }
 
-    console.log("Initialize private challenge tx signature", initTx);
+    // only the owner can set the platform fee
+    try {
+      await program.methods
+        .setPlatformFee(500, user1.publicKey)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
 
-    // Derive private challenge PDAs
-    const [privateChallenge] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge_account"),
-        privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
-      ],
-      program.programId
-    );
+      assert.fail("Should have failed because user1 is not the owner");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorizedOwner");
+    }
 
-    const [privateTreasury] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("treasury_account"),
-        privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
-      ],
-      program.programId
-    );
+    // the fee can't be more than the forfeited pool
+    try {
+      await program.methods
+        .setPlatformFee(10001, owner.publicKey)
+        .accounts({
+          signer: owner.publicKey,
+        })
+        .rpc();
+
+      assert.fail("Should have failed because the fee is too high");
+    } catch (error) {
+      assert.include(error.message, "FeeTooHigh");
+    }
+
+    // unclaimed funds are only swept from finalized challenges
+    try {
+      await program.methods
+        .sweepUnclaimed(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+          mint,
+          treasuryAccount,
+          feeRecipient: owner.publicKey,
+          creator: payer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not finalized");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotFinalized");
+    }
+  });
+
+  it("Close the accounts of a settled challenge", async () => {
+    // the outcome is kept until the challenge is finalized
+    try {
+      await program.methods
+        .closeUserChallenge(challengeId, user1.publicKey)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not finalized");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotFinalized");
+    }
+
+    // only the creator closes the challenge
+    try {
+      await program.methods
+        .closeChallenge(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+          mint,
+          treasuryAccount,
+          feeRecipient: owner.publicKey,
+          voteTallyAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because user1 is not the creator");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorizedCreator");
+    }
+  });
+
+  it("Sponsor a challenge", async () => {
+    const sponsorAmount = new BN(500000000);
+
+    try {
+      await program.methods
+        .sponsorChallenge(challengeId, new BN(0))
+        .accounts({
+          signer: user2.publicKey,
+          mint,
+          treasuryAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+      assert.fail("Should have failed because the amount is zero");
+    } catch (error) {
+      assert.include(error.message, "InvalidAmount");
+    }
+
+    await program.methods
+      .sponsorChallenge(challengeId, sponsorAmount)
+      .accounts({
+        signer: user2.publicKey,
+        mint,
+        treasuryAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    const challengeData = await program.account.challengeAccount.fetch(
+      challengeAccount
+    );
+    assert.equal(
+      challengeData.sponsorPool.toString(),
+      sponsorAmount.toString()
+    );
+
+    // sponsors are only refunded once the challenge is finalized without winners
+    try {
+      await program.methods
+        .refundSponsor(challengeId)
+        .accounts({
+          signer: user2.publicKey,
+          mint,
+          treasuryAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not finalized");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotFinalized");
+    }
+  });
+
+  it("Initialize a charity challenge with a beneficiary", async () => {
+    const charityChallengeId = new BN(11);
+    const [charityChallengeAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge_account"),
+        charityChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [charityTreasuryAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("treasury_account"),
+        charityChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initializeChallenge(
+        charityChallengeId,
+        { googleFit: { steps: new BN(10000) } },
+        "Run for charity",
+        "donate if you fail",
+        startTime,
+        endTime,
+        moneyPerParticipant,
+        false,
+        [],
+        defaultRules,
+        false
+      )
+      .accounts({
+        signer: payer.publicKey,
+        mint: mint,
+        beneficiary: payerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+
+    const challengeData = await program.account.challengeAccount.fetch(
+      charityChallengeAccount
+    );
+    assert.equal(challengeData.beneficiary.toString(), payerAta.toString());
+
+    // the forfeited pool is only known once the challenge is finalized
+    try {
+      await program.methods
+        .sweepToBeneficiary(charityChallengeId)
+        .accounts({
+          signer: user1.publicKey,
+          mint,
+          treasuryAccount: charityTreasuryAccount,
+          beneficiary: payerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not finalized");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotFinalized");
+    }
+  });
+
+  it("Initialize and join a native SOL challenge", async () => {
+    const nativeChallengeId = new BN(12);
+    const stake = new BN(LAMPORTS_PER_SOL);
+    const [nativeChallengeAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge_account"),
+        nativeChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nativeTreasuryAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("treasury_account"),
+        nativeChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initializeChallenge(
+        nativeChallengeId,
+        { googleFit: { steps: new BN(10000) } },
+        "Lets fit in SOL",
+        "do run 10000 steps",
+        startTime,
+        endTime,
+        stake,
+        false,
+        [],
+        defaultRules,
+        true // native
+      )
+      .accounts({
+        signer: payer.publicKey,
+        mint: null,
+        treasuryAccount: null,
+        nativeTreasuryAccount,
+        tokenProgram: null,
+      })
+      .signers([payer])
+      .rpc();
+
+    const challengeData = await program.account.challengeAccount.fetch(
+      nativeChallengeAccount
+    );
+    assert.equal(challengeData.isNative, true);
+    assert.equal(
+      challengeData.treasuryAccount.toString(),
+      nativeTreasuryAccount.toString()
+    );
+
+    // the stake is paid in lamports straight into the treasury
+    const treasuryBalanceBefore = await provider.connection.getBalance(
+      nativeTreasuryAccount
+    );
+    await program.methods
+      .joinChallenge(
+        nativeChallengeId,
+        "in SOL",
+        [],
+        null,
+        null
+      )
+      .accounts({
+        signer: user1.publicKey,
+        payer: user1.publicKey,
+        mint: null,
+        treasuryAccount: nativeTreasuryAccount,
+        userTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([user1])
+      .rpc();
+
+    const treasuryBalanceAfter = await provider.connection.getBalance(
+      nativeTreasuryAccount
+    );
+    assert.equal(
+      (treasuryBalanceAfter - treasuryBalanceBefore).toString(),
+      stake.toString()
+    );
+  });
+
+  it("Reject a Token-2022 mint with a permanent delegate", async () => {
+    const unsupportedChallengeId = new BN(13);
+    const delegatedMint = Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.PermanentDelegate]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+    const createMintTx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: delegatedMint.publicKey,
+        space: mintLen,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializePermanentDelegateInstruction(
+        delegatedMint.publicKey,
+        payer.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(
+        delegatedMint.publicKey,
+        9,
+        payer.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(provider.connection, createMintTx, [
+      payer,
+      delegatedMint,
+    ]);
+
+    // the delegate could take the stakes out of the treasury
+    try {
+      await program.methods
+        .initializeChallenge(
+          unsupportedChallengeId,
+          { googleFit: { steps: new BN(10000) } },
+          "Lets fit",
+          "do run 10000 steps",
+          startTime,
+          endTime,
+          moneyPerParticipant,
+          false,
+          [],
+          defaultRules,
+          false
+        )
+        .accounts({
+          signer: payer.publicKey,
+          mint: delegatedMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed because of the permanent delegate");
+    } catch (error) {
+      assert.include(error.message, "UnsupportedMint");
+    }
+  });
+
+  it("Join a challenge staked in a Token-2022 mint with a transfer fee", async () => {
+    const feeChallengeId = new BN(15);
+    const feeMint = Keypair.generate();
+    const feeBps = 100; // 1% of every transfer is withheld
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+    const createMintTx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: feeMint.publicKey,
+        space: mintLen,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        feeMint.publicKey,
+        payer.publicKey,
+        payer.publicKey,
+        feeBps,
+        BigInt(moneyPerParticipant.toString()),
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(
+        feeMint.publicKey,
+        9,
+        payer.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await sendAndConfirmTransaction(provider.connection, createMintTx, [
+      payer,
+      feeMint,
+    ]);
+
+    const user2FeeAta = await createAssociatedTokenAccount(
+      provider.connection,
+      user2,
+      feeMint.publicKey,
+      user2.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      feeMint.publicKey,
+      user2FeeAta,
+      payer.publicKey,
+      2 * LAMPORTS_PER_SOL,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const feeStartTime = new BN(Math.floor(Date.now() / 1000) + 60);
+    const feeEndTime = new BN(Math.floor(Date.now() / 1000) + 300);
+    await program.methods
+      .initializeChallenge(
+        feeChallengeId,
+        { googleFit: { steps: new BN(10000) } },
+        "Lets fit",
+        "do run 10000 steps",
+        feeStartTime,
+        feeEndTime,
+        moneyPerParticipant,
+        false,
+        [],
+        defaultRules,
+        false
+      )
+      .accounts({
+        signer: payer.publicKey,
+        mint: feeMint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+
+    const [feeChallengeAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge_account"),
+        feeChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const user2DataBefore = await program.account.userAccount.fetch(
+      user2Account
+    );
+    await program.methods
+      .joinChallenge(feeChallengeId, "I will try my best", [], null, null)
+      .accounts({
+        signer: user2.publicKey,
+        payer: user2.publicKey,
+        mint: feeMint.publicKey,
+        treasuryAccount: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("treasury_account"),
+            feeChallengeId.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0],
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    // the deposit is what the treasury received, the stake minus the transfer fee
+    const fee = moneyPerParticipant.muln(feeBps).divn(10000);
+    const depositAfterFee = moneyPerParticipant.sub(fee);
+    const userChallengeData = await program.account.userChallengeAccount.fetch(
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("user_challenge_account"),
+          user2.publicKey.toBytes(),
+          feeChallengeAccount.toBytes(),
+        ],
+        program.programId
+      )[0]
+    );
+    assert.equal(
+      userChallengeData.moneyDeposited.toString(),
+      depositAfterFee.toString()
+    );
+    const challengeData = await program.account.challengeAccount.fetch(
+      feeChallengeAccount
+    );
+    assert.equal(
+      challengeData.moneyPool.toString(),
+      depositAfterFee.toString()
+    );
+    // the totals of the user agree with the challenge
+    const user2DataAfter = await program.account.userAccount.fetch(
+      user2Account
+    );
+    assert.equal(
+      user2DataAfter.totalMoneyDeposited
+        .sub(user2DataBefore.totalMoneyDeposited)
+        .toString(),
+      depositAfterFee.toString()
+    );
+  });
+
+  it("Dispute the outcome of a challenge", async () => {
+    // disputes are opened only after the verification is over
+    try {
+      await program.methods
+        .openDispute(challengeId)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is under verification");
+    } catch (error) {
+      assert.include(error.message, "DisputeWindowNotOpen");
+    }
+
+    // only the owner can set the arbiter
+    try {
+      await program.methods
+        .setArbiter(user2.publicKey)
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because user1 is not the owner");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorizedOwner");
+    }
+  });
+
+  // Add test for private challenge initialization and joining
+  it("Initialize and join a private challenge", async () => {
+    // Initialize a private challenge
+    const privateGroupChallengeId = new BN(4);
+
+    // Define private group with only user1 allowed
+    const privateGroup = [user1.publicKey];
+
+    // Initialize the private challenge
+    const initTx = await program.methods
+      .initializeChallenge(
+        privateGroupChallengeId,
+        { voteBased: {} },
+        "Lets complete my homework",
+        "I will do homework for 10 hours",
+        startTime,
+        endTime,
+        moneyPerParticipant,
+        true, // private challenge
+        privateGroup, // only user1 is allowed
+        defaultRules,
+        false
+      )
+      .accounts({
+        signer: payer.publicKey,
+        voteTallyAccount: findVoteTallyAccount(privateGroupChallengeId),
+        mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+
+    console.log("Initialize private challenge tx signature", initTx);
+
+    // Derive private challenge PDAs
+    const [privateChallenge] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge_account"),
+        privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const [privateTreasury] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("treasury_account"),
+        privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
 
     const [user1PrivateChallengeAccount] = PublicKey.findProgramAddressSync(
       [
@@ -449,9 +1371,16 @@ describe("aaas-contract", () => {
 
     // User1 joins the private challenge (should succeed)
     const joinTx = await program.methods
-      .joinChallenge(privateGroupChallengeId, "User One", "I will try my best")
+      .joinChallenge(
+        privateGroupChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
       .accounts({
         signer: user1.publicKey,
+        payer: user1.publicKey,
 
         mint,
         treasuryAccount: privateTreasury,
@@ -468,11 +1397,14 @@ describe("aaas-contract", () => {
       await program.methods
         .joinChallenge(
           privateGroupChallengeId,
-          "User Two",
-          "I will try my best"
+          "I will try my best",
+          [],
+          null,
+          null
         )
         .accounts({
           signer: user2.publicKey,
+          payer: user2.publicKey,
 
           mint,
           treasuryAccount: privateTreasury,
@@ -490,6 +1422,153 @@ describe("aaas-contract", () => {
     }
   });
 
+  it("Join a private challenge through the merkle allowlist", async () => {
+    const allowlistChallengeId = new BN(10);
+
+    // two leaves allowlist, the proof of one leaf is the other leaf
+    const leaf = (address: PublicKey) =>
+      createHash("sha256")
+        .update(Buffer.from([0]))
+        .update(address.toBuffer())
+        .digest();
+    const [first, second] = [leaf(user1.publicKey), leaf(user2.publicKey)].sort(
+      Buffer.compare
+    );
+    const root = createHash("sha256")
+      .update(Buffer.from([1]))
+      .update(first)
+      .update(second)
+      .digest();
+
+    await program.methods
+      .initializeChallenge(
+        allowlistChallengeId,
+        { googleFit: { steps: new BN(10000) } },
+        "Company walk",
+        "do run 10000 steps",
+        startTime,
+        endTime,
+        moneyPerParticipant,
+        true, // private challenge
+        [], // members are in the allowlist instead
+        { ...defaultRules, allowlistRoot: Array.from(root) },
+        false
+      )
+      .accounts({
+        signer: payer.publicKey,
+        mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+
+    const [allowlistTreasury] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("treasury_account"),
+        allowlistChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    // user2 joins with the proof of its leaf
+    await program.methods
+      .joinChallenge(
+        allowlistChallengeId,
+        "I will try my best",
+        [Array.from(leaf(user1.publicKey))],
+        null,
+        null
+      )
+      .accounts({
+        signer: user2.publicKey,
+        payer: user2.publicKey,
+        mint,
+        treasuryAccount: allowlistTreasury,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    // user1 can't join with a wrong proof
+    try {
+      await program.methods
+        .joinChallenge(
+          allowlistChallengeId,
+          "I will try my best",
+          [Array.from(leaf(user1.publicKey))],
+          null,
+          null
+        )
+        .accounts({
+          signer: user1.publicKey,
+          payer: user1.publicKey,
+          mint,
+          treasuryAccount: allowlistTreasury,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because the merkle proof is wrong");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorized");
+    }
+  });
+
+  it("Join a private challenge with an invite from the creator", async () => {
+    const privateGroupChallengeId = new BN(4);
+    const [privateTreasury] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("treasury_account"),
+        privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    // the creator (payer) signs challenge_id || invitee || expiry off-chain
+    const inviteExpiry = new BN(Math.floor(Date.now() / 1000) + 3600);
+    const message = Buffer.concat([
+      privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
+      user2.publicKey.toBuffer(),
+      inviteExpiry.toArrayLike(Buffer, "le", 8),
+    ]);
+    const verifyInviteIx = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: payer.secretKey,
+      message,
+    });
+
+    // user2 is not in the private group but is invited
+    await program.methods
+      .joinChallenge(
+        privateGroupChallengeId,
+        "I will try my best",
+        [],
+        inviteExpiry,
+        null
+      )
+      .accounts({
+        signer: user2.publicKey,
+        payer: user2.publicKey,
+        mint,
+        treasuryAccount: privateTreasury,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([verifyInviteIx])
+      .signers([user2])
+      .rpc();
+
+    const challengeData = await program.account.challengeAccount.fetch(
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("challenge_account"),
+          privateGroupChallengeId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0]
+    );
+    assert.equal(challengeData.totalParticipants.toString(), "2");
+  });
+
   // Test multiple users interacting with a challenge
   it("Multiple users joining a challenge", async () => {
     // Initialize a new challenge for multiple users
@@ -505,7 +1584,9 @@ describe("aaas-contract", () => {
         endTime,
         moneyPerParticipant,
         false, // not private
-        [] // no private group
+        [], // no private group
+        defaultRules,
+        false
       )
       .accounts({
         signer: payer.publicKey,
@@ -534,9 +1615,16 @@ describe("aaas-contract", () => {
 
     // User1 joins the multi-user challenge
     await program.methods
-      .joinChallenge(multiUserChallengeId, "User One", "I will try my best")
+      .joinChallenge(
+        multiUserChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
       .accounts({
         signer: user1.publicKey,
+        payer: user1.publicKey,
 
         mint,
         treasuryAccount: multiTreasury,
@@ -546,20 +1634,44 @@ describe("aaas-contract", () => {
       .signers([user1])
       .rpc();
 
-    // User2 joins the multi-user challenge
+    // User2 joins the multi-user challenge, the payer sponsors the rent
     await program.methods
-      .joinChallenge(multiUserChallengeId, "User Two", "I will try my best")
+      .joinChallenge(
+        multiUserChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
       .accounts({
         signer: user2.publicKey,
+        payer: payer.publicKey,
 
         mint,
         treasuryAccount: multiTreasury,
 
         tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([user2])
+      .signers([user2, payer])
       .rpc();
 
+    const [user2MultiChallengeAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_challenge_account"),
+        user2.publicKey.toBuffer(),
+        multiChallenge.toBuffer(),
+      ],
+      program.programId
+    );
+    const user2ChallengeData =
+      await program.account.userChallengeAccount.fetch(
+        user2MultiChallengeAccount
+      );
+    assert.equal(
+      user2ChallengeData.rentPayer.toString(),
+      payer.publicKey.toString()
+    );
+
     // Verify the challenge has 2 participants and correct money pool
     const challengeData = await program.account.challengeAccount.fetch(
       multiChallenge
@@ -587,10 +1699,13 @@ describe("aaas-contract", () => {
         endTime,
         moneyPerParticipant,
         false,
-        []
+        [],
+        defaultRules,
+        false
       )
       .accounts({
         signer: payer.publicKey,
+        voteTallyAccount: findVoteTallyAccount(voteBasedChallengeId),
         mint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -626,97 +1741,395 @@ describe("aaas-contract", () => {
     const [user2VoteChallengeAccount] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("user_challenge_account"),
-        user2.publicKey.toBytes(),
+        user2.publicKey.toBytes(),
+        voteChallenge.toBytes(),
+      ],
+      program.programId
+    );
+
+    // Both users join the vote-based challenge
+    // User1 joins
+    await program.methods
+      .joinChallenge(
+        voteBasedChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
+      .accounts({
+        signer: user1.publicKey,
+        payer: user1.publicKey,
+
+        mint,
+        treasuryAccount: voteTreasury,
+
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user1])
+      .rpc();
+
+    // User2 joins
+    await program.methods
+      .joinChallenge(
+        voteBasedChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
+      .accounts({
+        signer: user2.publicKey,
+        payer: user2.publicKey,
+        mint,
+        treasuryAccount: voteTreasury,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user2])
+      .rpc();
+
+    // Test that user can't vote for themselves
+    try {
+      await program.methods
+        .voteForVoteBasedChallenge(voteBasedChallengeId, user1.publicKey, {
+          voteBased: { isCompleted: true },
+        })
+        .accounts({
+          signer: user1.publicKey,
+          voter: user1.publicKey,
+          sessionKeyAccount: null,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because user is voting for themselves");
+    } catch (error) {
+      assert.include(error.message, "VoterIsVotingForHimself");
+    }
+
+    // Test challenge timing validation with manual clock manipulation
+    // This test would simulate time passing if we could manipulate the onchain clock
+    // Since we can't easily do that in a test, we'll just verify the error conditions
+
+    // Test that user can't update challenge status before it's ended
+    try {
+      await program.methods
+        .updateChallengeStatus(voteBasedChallengeId, user1.publicKey, {
+          challengeType: {
+            monitored: {
+              score: new BN(8000),
+              isCompleted: true,
+            },
+          },
+        })
+        .accounts({
+          signer: owner.publicKey,
+        })
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not ended yet");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotEnded");
+    }
+  });
+
+  it("Submit proof for a challenge", async () => {
+    const voteBasedChallengeId = new BN(6);
+    const proofHash = Array.from(Buffer.alloc(32, 1));
+
+    // proofs can't be submitted while the challenge is running
+    try {
+      await program.methods
+        .submitProof(voteBasedChallengeId, proofHash, "https://example.com/proof")
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not ended yet");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotEnded");
+    }
+
+    // monitored challenges are verified by the verification service
+    try {
+      await program.methods
+        .submitProof(challengeId, proofHash, "https://example.com/proof")
+        .accounts({
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not vote based");
+    } catch (error) {
+      assert.include(error.message, "InvalidVerificationType");
+    }
+  });
+
+  it("Batch vote for a vote-based challenge", async () => {
+    const voteBasedChallengeId = new BN(6);
+    const [voteChallenge] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge_account"),
+        voteBasedChallengeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [user1VoteChallengeAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_challenge_account"),
+        user1.publicKey.toBytes(),
         voteChallenge.toBytes(),
       ],
       program.programId
     );
 
-    // The vote account that user2 will create for user1
-    const [voteAccount] = PublicKey.findProgramAddressSync(
+    try {
+      await program.methods
+        .batchVoteForVoteBasedChallenge(voteBasedChallengeId, [
+          { userAddress: user1.publicKey, isCompleted: true },
+        ])
+        .accounts({
+          signer: user2.publicKey,
+          voter: user2.publicKey,
+          sessionKeyAccount: null,
+        })
+        .remainingAccounts([
+          {
+            pubkey: user1VoteChallengeAccount,
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .signers([user2])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not ended yet");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotEnded");
+    }
+
+    // the PDA of user1 in another challenge can't stand for user1 in this one,
+    // and the valid vote of the batch is not counted either
+    const [user1OtherChallengeAccount] = PublicKey.findProgramAddressSync(
       [
-        Buffer.from("vote_account"),
-        voteChallenge.toBytes(),
+        Buffer.from("user_challenge_account"),
         user1.publicKey.toBytes(),
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("challenge_account"),
+            new BN(5).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0].toBytes(),
       ],
       program.programId
     );
+    try {
+      await program.methods
+        .batchVoteForVoteBasedChallenge(voteBasedChallengeId, [
+          { userAddress: user1.publicKey, isCompleted: true },
+          { userAddress: user1.publicKey, isCompleted: false },
+        ])
+        .accounts({
+          signer: user2.publicKey,
+          voter: user2.publicKey,
+          sessionKeyAccount: null,
+        })
+        .remainingAccounts([
+          {
+            pubkey: user1VoteChallengeAccount,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: user1OtherChallengeAccount,
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .signers([user2])
+        .rpc();
 
-    // Both users join the vote-based challenge
-    // User1 joins
-    await program.methods
-      .joinChallenge(
-        voteBasedChallengeId,
-        "Vote User One",
-        "I will try my best"
-      )
-      .accounts({
-        signer: user1.publicKey,
+      assert.fail("Should have failed because of the PDA of another challenge");
+    } catch (error) {
+      assert.include(error.message, "InvalidRemainingAccounts");
+    }
+    const voteTally = await program.account.voteTallyAccount.fetch(
+      findVoteTallyAccount(voteBasedChallengeId)
+    );
+    assert.isTrue(voteTally.voteInPositive.every((votes) => votes === 0));
+  });
 
-        mint,
-        treasuryAccount: voteTreasury,
+  it("Only participants vote", async () => {
+    // a wallet outside the challenge has no row in the vote tally
+    try {
+      await program.methods
+        .voteForVoteBasedChallenge(new BN(6), user1.publicKey, {
+          voteBased: { isCompleted: true },
+        })
+        .accounts({
+          signer: payer.publicKey,
+          voter: payer.publicKey,
+          sessionKeyAccount: null,
+        })
+        .signers([payer])
+        .rpc();
 
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([user1])
-      .rpc();
+      assert.fail("Should have failed because the payer did not participate");
+    } catch (error) {
+      assert.include(error.message, "AccountNotInitialized");
+    }
+  });
+
+  it("Vote with a session key", async () => {
+    const voteBasedChallengeId = new BN(6);
+    const sessionKey = Keypair.generate();
+    const now = Math.floor(Date.now() / 1000);
+
+    // session keys are short lived
+    try {
+      await program.methods
+        .createSessionKey(sessionKey.publicKey, new BN(now + 30 * 24 * 60 * 60))
+        .accounts({
+          signer: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      assert.fail("Should have failed because the session is too long");
+    } catch (error) {
+      assert.include(error.message, "InvalidSessionExpiry");
+    }
+
+    // a delegate can't vote for the wallet without a session key
+    try {
+      await program.methods
+        .voteForVoteBasedChallenge(voteBasedChallengeId, user1.publicKey, {
+          voteBased: { isCompleted: true },
+        })
+        .accounts({
+          signer: sessionKey.publicKey,
+          voter: user2.publicKey,
+          sessionKeyAccount: null,
+        })
+        .signers([sessionKey])
+        .rpc();
+
+      assert.fail("Should have failed because the delegate has no session key");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorized");
+    }
 
-    // User2 joins
     await program.methods
-      .joinChallenge(
-        voteBasedChallengeId,
-        "Vote User Two",
-        "I will try my best"
-      )
+      .createSessionKey(sessionKey.publicKey, new BN(now + 60 * 60))
       .accounts({
         signer: user2.publicKey,
-        mint,
-        treasuryAccount: voteTreasury,
-        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([user2])
       .rpc();
 
-    // Test that user can't vote for themselves
+    const [sessionKeyAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("session_key"),
+        user2.publicKey.toBuffer(),
+        sessionKey.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // the session key passes for the wallet, the vote then fails on the voting window
     try {
       await program.methods
         .voteForVoteBasedChallenge(voteBasedChallengeId, user1.publicKey, {
           voteBased: { isCompleted: true },
         })
+        .accounts({
+          signer: sessionKey.publicKey,
+          voter: user2.publicKey,
+          sessionKeyAccount,
+        })
+        .signers([sessionKey])
+        .rpc();
+
+      assert.fail("Should have failed because challenge is not ended yet");
+    } catch (error) {
+      assert.include(error.message, "ChallengeNotEnded");
+    }
+
+    await program.methods
+      .revokeSessionKey(sessionKey.publicKey)
+      .accounts({
+        signer: user2.publicKey,
+      })
+      .signers([user2])
+      .rpc();
+
+    const revokedSessionKey = await provider.connection.getAccountInfo(
+      sessionKeyAccount
+    );
+    assert.isNull(revokedSessionKey);
+  });
+
+  it("Draw a jury for a vote-based challenge", async () => {
+    // challenges without a jury let every participant vote
+    try {
+      await program.methods
+        .drawJury(new BN(6))
         .accounts({
           signer: user1.publicKey,
         })
         .signers([user1])
         .rpc();
 
-      assert.fail("Should have failed because user is voting for themselves");
+      assert.fail("Should have failed because challenge has no jury");
     } catch (error) {
-      assert.include(error.message, "VoterIsVotingForHimself");
+      assert.include(error.message, "NoJury");
     }
 
-    // Test challenge timing validation with manual clock manipulation
-    // This test would simulate time passing if we could manipulate the onchain clock
-    // Since we can't easily do that in a test, we'll just verify the error conditions
-
-    // Test that user can't update challenge status before it's ended
     try {
       await program.methods
-        .updateChallengeStatus(voteBasedChallengeId, user1.publicKey, {
-          challengeType: {
-            monitored: {
-              score: new BN(8000),
-              isCompleted: true,
-            },
-          },
+        .requestJury(new BN(6))
+        .accounts({
+          signer: user1.publicKey,
         })
+        .signers([user1])
+        .rpc();
+
+      assert.fail("Should have failed because challenge has no jury");
+    } catch (error) {
+      assert.include(error.message, "NoJury");
+    }
+
+    try {
+      await program.methods
+        .initializeChallenge(
+          new BN(9),
+          { voteBased: {} },
+          "Read book",
+          "I will read book for 2 hours",
+          startTime,
+          endTime,
+          moneyPerParticipant,
+          false,
+          [],
+          { ...defaultRules, jurySize: 10 },
+          false
+        )
         .accounts({
-          signer: owner.publicKey,
+          signer: payer.publicKey,
+          voteTallyAccount: findVoteTallyAccount(new BN(9)),
+          mint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([payer])
         .rpc();
 
-      assert.fail("Should have failed because challenge is not ended yet");
+      assert.fail("Should have failed because jury is too large");
     } catch (error) {
-      assert.include(error.message, "ChallengeNotEnded");
+      assert.include(error.message, "JurySizeTooLarge");
     }
   });
 
@@ -738,7 +2151,9 @@ describe("aaas-contract", () => {
           futureEndTime,
           moneyPerParticipant,
           false,
-          []
+          [],
+          defaultRules,
+          false
         )
         .accounts({
           signer: payer.publicKey,
@@ -768,7 +2183,9 @@ describe("aaas-contract", () => {
           earlierEndTime,
           moneyPerParticipant,
           false,
-          []
+          [],
+          defaultRules,
+          false
         )
         .accounts({
           signer: payer.publicKey,
@@ -795,7 +2212,9 @@ describe("aaas-contract", () => {
           endTime,
           moneyPerParticipant,
           true, // private
-          [] // empty private group
+          [], // empty private group
+          defaultRules,
+          false
         )
         .accounts({
           signer: payer.publicKey,
@@ -811,6 +2230,143 @@ describe("aaas-contract", () => {
     } catch (error) {
       assert.include(error.message, "PrivateGroupEmpty");
     }
+
+    // invite only challenges are private without a group or an allowlist
+    const inviteOnlyChallengeId = new BN(14);
+    await program.methods
+      .initializeChallenge(
+        inviteOnlyChallengeId,
+        { googleFit: { steps: new BN(10000) } },
+        "Lets fit",
+        "do run 10000 steps",
+        startTime,
+        endTime,
+        moneyPerParticipant,
+        true,
+        [],
+        { ...defaultRules, inviteOnly: true },
+        false
+      )
+      .accounts({
+        signer: payer.publicKey,
+        mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([payer])
+      .rpc();
+
+    // nobody joins without an invite of the creator
+    try {
+      await program.methods
+        .joinChallenge(inviteOnlyChallengeId, "I will try my best", [], null, null)
+        .accounts({
+          signer: user2.publicKey,
+          payer: user2.publicKey,
+          mint,
+          treasuryAccount: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("treasury_account"),
+              inviteOnlyChallengeId.toArrayLike(Buffer, "le", 8),
+            ],
+            program.programId
+          )[0],
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+      assert.fail("Should have failed because user2 has no invite");
+    } catch (error) {
+      assert.include(error.message, "UnAuthorized");
+    }
+
+    // Test initialization with a payout distribution that doesn't cover the whole pool
+    try {
+      await program.methods
+        .initializeChallenge(
+          invalidChallengeId,
+          { googleFit: { steps: new BN(10000) } },
+          "Lets fit",
+          "do run 10000 steps",
+          startTime,
+          endTime,
+          moneyPerParticipant,
+          false,
+          [],
+          { ...defaultRules, payoutDistribution: [5000, 3000, 1000] },
+          false
+        )
+        .accounts({
+          signer: payer.publicKey,
+          mint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed due to invalid payout distribution");
+    } catch (error) {
+      assert.include(error.message, "InvalidPayoutDistribution");
+    }
+
+    // Test initialization with a claim window too short for the winners to claim
+    try {
+      await program.methods
+        .initializeChallenge(
+          invalidChallengeId,
+          { googleFit: { steps: new BN(10000) } },
+          "Lets fit",
+          "do run 10000 steps",
+          startTime,
+          endTime,
+          moneyPerParticipant,
+          false,
+          [],
+          { ...defaultRules, claimDuration: new BN(1) },
+          false
+        )
+        .accounts({
+          signer: payer.publicKey,
+          mint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed due to invalid claim duration");
+    } catch (error) {
+      assert.include(error.message, "InvalidClaimDuration");
+    }
+
+    // Test initialization of a proportional challenge that has no score
+    try {
+      await program.methods
+        .initializeChallenge(
+          invalidChallengeId,
+          { voteBased: {} },
+          "Read more",
+          "read 100 pages",
+          startTime,
+          endTime,
+          moneyPerParticipant,
+          false,
+          [],
+          { ...defaultRules, isProportional: true },
+          false
+        )
+        .accounts({
+          signer: payer.publicKey,
+          mint,
+          voteTallyAccount: findVoteTallyAccount(invalidChallengeId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+
+      assert.fail("Should have failed due to proportional vote based challenge");
+    } catch (error) {
+      assert.include(error.message, "InvalidVerificationType");
+    }
   });
 
   // Test double joining a challenge
@@ -828,7 +2384,9 @@ describe("aaas-contract", () => {
         endTime,
         moneyPerParticipant,
         false,
-        []
+        [],
+        defaultRules,
+        false
       )
       .accounts({
         signer: payer.publicKey,
@@ -857,9 +2415,16 @@ describe("aaas-contract", () => {
 
     // User1 joins the challenge
     await program.methods
-      .joinChallenge(doubleJoinChallengeId, "User One", "I will try my best")
+      .joinChallenge(
+        doubleJoinChallengeId,
+        "I will try my best",
+        [],
+        null,
+        null
+      )
       .accounts({
         signer: user1.publicKey,
+        payer: user1.publicKey,
         mint,
         treasuryAccount: doubleJoinTreasury,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -872,11 +2437,14 @@ describe("aaas-contract", () => {
       await program.methods
         .joinChallenge(
           doubleJoinChallengeId,
-          "User One Again",
-          "I will try my best"
+          "I will try my best",
+          [],
+          null,
+          null
         )
         .accounts({
           signer: user1.publicKey,
+          payer: user1.publicKey,
 
           mint,
           treasuryAccount: doubleJoinTreasury,
@@ -0,0 +1,1023 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { AaasContract } from "../target/types/aaas-contract";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  createAssociatedTokenAccount,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { BN } from "bn.js";
+import { createHash } from "crypto";
+
+// these tests wait for the time windows of the challenges to pass, so they need
+// the program built with the short windows of the short-durations feature:
+//   SHORT_DURATIONS=1 anchor test -- --features short-durations
+const describeEndToEnd = process.env.SHORT_DURATIONS ? describe : describe.skip;
+
+describeEndToEnd("aaas-contract end to end", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const provider = anchor.AnchorProvider.env();
+  const program = anchor.workspace.AaasContract as Program<AaasContract>;
+
+  // owner of the contract
+  const owner = provider.wallet;
+
+  // windows of the short-durations build, see constants.rs
+  const VERIFICATION_DURATION = 6;
+  const PROOF_SUBMISSION_DURATION = 4;
+  const DISPUTE_DURATION = 6;
+  const DISPUTE_RESOLUTION_DURATION = DISPUTE_DURATION;
+  const MIN_CLAIM_DURATION = 4;
+  const DISPUTE_BOND = 100000000;
+
+  const stake = new BN(1000000000); // 1 token with 9 decimals
+  const platformFeeBps = 500;
+  const defaultRules = {
+    requiresProof: false,
+    jurySize: 0,
+    allowlistRoot: null,
+    creatorFeeBps: 0,
+    payoutDistribution: [],
+    isProportional: false,
+    maxStake: new BN(0),
+    forfeitRefundBps: 0,
+    inviteOnly: false,
+    claimDuration: new BN(7 * 24 * 60 * 60),
+  };
+
+  const creator = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const feeRecipient = Keypair.generate();
+  const charity = Keypair.generate();
+  const sponsor = Keypair.generate();
+
+  // participants of the monitored challenge
+  const winner = Keypair.generate();
+  const upheldDisputer = Keypair.generate();
+  const quitter = Keypair.generate();
+  const expiredDisputer = Keypair.generate();
+  // participant of the charity challenge
+  const donor = Keypair.generate();
+  // participants of the vote based challenge
+  const prover = Keypair.generate();
+  const skipper = Keypair.generate();
+
+  let mint: PublicKey;
+
+  const sleep = (ms: number) =>
+    new Promise((resolve) => setTimeout(resolve, ms));
+
+  // the program sees the time of the clock sysvar, not the local time
+  const clockTime = async () => {
+    const clock = await provider.connection.getAccountInfo(
+      SYSVAR_CLOCK_PUBKEY
+    );
+    return new BN(clock.data.subarray(32, 40), "le").toNumber();
+  };
+
+  const waitFor = async (timestamp: number) => {
+    while ((await clockTime()) < timestamp) {
+      await sleep(500);
+    }
+  };
+
+  const findChallengeAccount = (id: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("challenge_account"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const findTreasuryAccount = (id: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_account"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const findVoteTallyAccount = (id: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("vote_tally_account"), findChallengeAccount(id).toBytes()],
+      program.programId
+    )[0];
+
+  const findUserAccount = (user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("user_account"), user.toBytes()],
+      program.programId
+    )[0];
+
+  const findUserChallengeAccount = (id: BN, user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_challenge_account"),
+        user.toBytes(),
+        findChallengeAccount(id).toBytes(),
+      ],
+      program.programId
+    )[0];
+
+  const findDisputeAccount = (id: BN, user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("dispute_account"),
+        findChallengeAccount(id).toBytes(),
+        user.toBytes(),
+      ],
+      program.programId
+    )[0];
+
+  const findSponsorAccount = (id: BN, user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("sponsor_account"),
+        findChallengeAccount(id).toBytes(),
+        user.toBytes(),
+      ],
+      program.programId
+    )[0];
+
+  // the user name registry is keyed by the hash of the lowercase name
+  const findUserNameAccount = (userName: string) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_name"),
+        createHash("sha256").update(userName.toLowerCase()).digest(),
+      ],
+      program.programId
+    )[0];
+
+  // token accounts created by the program hold nothing before they exist
+  const tokenBalance = async (user: PublicKey) => {
+    const tokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(mint, user)
+    ).catch(() => null);
+    return tokenAccount ? Number(tokenAccount.amount) : 0;
+  };
+
+  const airdrop = async (user: PublicKey) => {
+    const signature = await provider.connection.requestAirdrop(
+      user,
+      5 * LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+  };
+
+  const initializeChallenge = async (
+    id: BN,
+    challengeType: any,
+    startTime: number,
+    endTime: number,
+    rules: any,
+    accounts: any
+  ) =>
+    program.methods
+      .initializeChallenge(
+        id,
+        challengeType,
+        "End to end",
+        "run the whole challenge",
+        new BN(startTime),
+        new BN(endTime),
+        stake,
+        false,
+        [],
+        rules,
+        false
+      )
+      .accounts({
+        signer: creator.publicKey,
+        mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ...accounts,
+      })
+      .signers([creator])
+      .rpc();
+
+  const joinChallenge = async (id: BN, user: Keypair) =>
+    program.methods
+      .joinChallenge(id, "I will try my best", [], null, null)
+      .accounts({
+        signer: user.publicKey,
+        payer: user.publicKey,
+        mint,
+        treasuryAccount: findTreasuryAccount(id),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+  // participants are settled in the order they joined
+  const settleParticipants = async (
+    id: BN,
+    users: Keypair[],
+    voteTallyAccount: PublicKey | null
+  ) =>
+    program.methods
+      .settleParticipants(id)
+      .accounts({
+        signer: creator.publicKey,
+        voteTallyAccount,
+      })
+      .remainingAccounts(
+        [].concat(
+          ...users.map((user) => [
+            {
+              pubkey: findUserChallengeAccount(id, user.publicKey),
+              isSigner: false,
+              isWritable: true,
+            },
+            {
+              pubkey: findUserAccount(user.publicKey),
+              isSigner: false,
+              isWritable: true,
+            },
+          ])
+        )
+      )
+      .signers([creator])
+      .rpc();
+
+  const claimChallenge = async (id: BN, user: Keypair) =>
+    program.methods
+      .claimChallenge(id)
+      .accounts({
+        signer: user.publicKey,
+        mint,
+        treasuryAccount: findTreasuryAccount(id),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+  const claimFees = async (id: BN) =>
+    program.methods
+      .claimFees(id)
+      .accounts({
+        signer: creator.publicKey,
+        mint,
+        treasuryAccount: findTreasuryAccount(id),
+        feeRecipient: feeRecipient.publicKey,
+        creator: creator.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([creator])
+      .rpc();
+
+  const closeUserChallenge = async (id: BN, user: Keypair) =>
+    program.methods
+      .closeUserChallenge(id, user.publicKey)
+      .accounts({
+        signer: user.publicKey,
+        rentPayer: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+
+  const closeChallenge = async (
+    id: BN,
+    voteTallyAccount: PublicKey | null
+  ) =>
+    program.methods
+      .closeChallenge(id)
+      .accounts({
+        signer: creator.publicKey,
+        mint,
+        treasuryAccount: findTreasuryAccount(id),
+        feeRecipient: feeRecipient.publicKey,
+        voteTallyAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([creator])
+      .rpc();
+
+  before(async () => {
+    const participants = [
+      winner,
+      upheldDisputer,
+      quitter,
+      expiredDisputer,
+      donor,
+      prover,
+      skipper,
+    ];
+    for (const user of [creator, arbiter, sponsor, ...participants]) {
+      await airdrop(user.publicKey);
+    }
+
+    mint = await createMint(
+      provider.connection,
+      creator,
+      creator.publicKey,
+      null,
+      9
+    );
+    for (const user of [creator, sponsor, ...participants]) {
+      const userAta = await createAssociatedTokenAccount(
+        provider.connection,
+        creator,
+        mint,
+        user.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        creator,
+        mint,
+        userAta,
+        creator,
+        10 * stake.toNumber()
+      );
+    }
+    await createAssociatedTokenAccount(
+      provider.connection,
+      creator,
+      mint,
+      charity.publicKey
+    );
+
+    // the contract is already initialized when the other test files ran first
+    const [state] = PublicKey.findProgramAddressSync(
+      [Buffer.from("program_owner")],
+      program.programId
+    );
+    if (!(await program.account.programState.fetchNullable(state))) {
+      await program.methods
+        .initialize()
+        .accounts({
+          signer: owner.publicKey,
+        })
+        .rpc();
+    }
+    // the platform fee is copied into the challenges when they are created
+    await program.methods
+      .setPlatformFee(platformFeeBps, feeRecipient.publicKey)
+      .accounts({
+        signer: owner.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .setArbiter(arbiter.publicKey)
+      .accounts({
+        signer: owner.publicKey,
+      })
+      .rpc();
+
+    for (const [index, user] of participants.entries()) {
+      const userName = `End to end ${index}`;
+      await program.methods
+        .registerUser(userName, "", Array(32).fill(0))
+        .accounts({
+          signer: user.publicKey,
+          payer: user.publicKey,
+          userNameAccount: findUserNameAccount(userName),
+        })
+        .signers([user])
+        .rpc();
+    }
+  });
+
+  describe("a monitored challenge", () => {
+    const challengeId = new BN(100);
+    const challengeAccount = findChallengeAccount(challengeId);
+    const creatorFeeBps = 1000;
+    let startTime: number;
+    let endTime: number;
+    const verificationEndTime = () => endTime + VERIFICATION_DURATION;
+    const disputeEndTime = () => verificationEndTime() + DISPUTE_DURATION;
+
+    it("Join the challenge and quit it while it runs", async () => {
+      startTime = (await clockTime()) + 12;
+      endTime = startTime + 5;
+      await initializeChallenge(
+        challengeId,
+        { googleFit: { steps: new BN(10000) } },
+        startTime,
+        endTime,
+        { ...defaultRules, creatorFeeBps, forfeitRefundBps: 5000 },
+        {}
+      );
+      for (const user of [winner, upheldDisputer, quitter, expiredDisputer]) {
+        await joinChallenge(challengeId, user);
+      }
+
+      await waitFor(startTime);
+      const quitterBalanceBefore = await tokenBalance(quitter.publicKey);
+      await program.methods
+        .forfeitChallenge(challengeId)
+        .accounts({
+          signer: quitter.publicKey,
+          mint,
+          treasuryAccount: findTreasuryAccount(challengeId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([quitter])
+        .rpc();
+
+      // half of the deposit is given back to participants who quit
+      assert.equal(
+        (await tokenBalance(quitter.publicKey)) - quitterBalanceBefore,
+        stake.toNumber() / 2
+      );
+    });
+
+    it("Report the outcomes once the challenge ended", async () => {
+      await waitFor(endTime);
+      await program.methods
+        .updateChallengeStatus(challengeId, winner.publicKey, {
+          challengeType: {
+            monitored: {
+              score: new BN(12000),
+              isCompleted: true,
+            },
+          },
+        })
+        .accounts({
+          signer: owner.publicKey,
+        })
+        .rpc();
+
+      // participants who quit have no outcome to verify
+      try {
+        await program.methods
+          .updateChallengeStatus(challengeId, quitter.publicKey, {
+            challengeType: {
+              monitored: {
+                score: new BN(12000),
+                isCompleted: true,
+              },
+            },
+          })
+          .accounts({
+            signer: owner.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Should have failed because the participant quit");
+      } catch (error) {
+        assert.include(error.message, "AlreadyForfeited");
+      }
+    });
+
+    it("Resolve a dispute and let another one expire", async () => {
+      await waitFor(verificationEndTime());
+
+      // winners have nothing to dispute
+      try {
+        await program.methods
+          .openDispute(challengeId)
+          .accounts({
+            signer: winner.publicKey,
+            voteTallyAccount: null,
+          })
+          .signers([winner])
+          .rpc();
+
+        assert.fail("Should have failed because the participant won");
+      } catch (error) {
+        assert.include(error.message, "NothingToDispute");
+      }
+
+      for (const user of [upheldDisputer, expiredDisputer]) {
+        await program.methods
+          .openDispute(challengeId)
+          .accounts({
+            signer: user.publicKey,
+            voteTallyAccount: null,
+          })
+          .signers([user])
+          .rpc();
+      }
+
+      // only the owner and the arbiter judge the disputes
+      try {
+        await program.methods
+          .resolveDispute(challengeId, upheldDisputer.publicKey, true)
+          .accounts({
+            signer: winner.publicKey,
+            user: upheldDisputer.publicKey,
+            owner: owner.publicKey,
+          })
+          .signers([winner])
+          .rpc();
+
+        assert.fail("Should have failed because the signer is not the arbiter");
+      } catch (error) {
+        assert.include(error.message, "UnAuthorizedArbiter");
+      }
+
+      await program.methods
+        .resolveDispute(challengeId, upheldDisputer.publicKey, true)
+        .accounts({
+          signer: arbiter.publicKey,
+          user: upheldDisputer.publicKey,
+          owner: owner.publicKey,
+        })
+        .signers([arbiter])
+        .rpc();
+
+      const userChallengeData =
+        await program.account.userChallengeAccount.fetch(
+          findUserChallengeAccount(challengeId, upheldDisputer.publicKey)
+        );
+      assert.equal(userChallengeData.isChallengeCompleted, true);
+      assert.isNull(
+        await program.account.disputeAccount.fetchNullable(
+          findDisputeAccount(challengeId, upheldDisputer.publicKey)
+        )
+      );
+
+      // the open dispute holds the outcomes until the arbiter's deadline
+      await waitFor(disputeEndTime());
+      try {
+        await settleParticipants(
+          challengeId,
+          [winner, upheldDisputer, quitter, expiredDisputer],
+          null
+        );
+
+        assert.fail("Should have failed because a dispute is still open");
+      } catch (error) {
+        assert.include(error.message, "ChallengeUnderDispute");
+      }
+    });
+
+    it("Settle the participants and pay the winners", async () => {
+      await waitFor(disputeEndTime() + DISPUTE_RESOLUTION_DURATION + 1);
+
+      // large challenges are settled over several transactions
+      await settleParticipants(challengeId, [winner, upheldDisputer], null);
+      try {
+        await program.methods
+          .finalizeChallenge(challengeId)
+          .accounts({
+            signer: creator.publicKey,
+          })
+          .signers([creator])
+          .rpc();
+
+        assert.fail("Should have failed because participants are not settled");
+      } catch (error) {
+        assert.include(error.message, "ParticipantsNotSettled");
+      }
+      await settleParticipants(challengeId, [quitter, expiredDisputer], null);
+      await program.methods
+        .finalizeChallenge(challengeId)
+        .accounts({
+          signer: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      // the winners share the deposit of the loser and what the quitter left
+      const forfeitedPool = stake.toNumber() + stake.toNumber() / 2;
+      const platformFee = (forfeitedPool * platformFeeBps) / 10000;
+      const creatorFee = (forfeitedPool * creatorFeeBps) / 10000;
+      const challengeData = await program.account.challengeAccount.fetch(
+        challengeAccount
+      );
+      assert.equal(challengeData.isFinalized, true);
+      assert.equal(challengeData.totalWinners.toNumber(), 2);
+      assert.equal(challengeData.forfeitedPool.toNumber(), forfeitedPool);
+      assert.equal(challengeData.platformFee.toNumber(), platformFee);
+      assert.equal(challengeData.creatorFee.toNumber(), creatorFee);
+
+      const reward = (forfeitedPool - platformFee - creatorFee) / 2;
+      for (const user of [winner, upheldDisputer]) {
+        const balanceBefore = await tokenBalance(user.publicKey);
+        await claimChallenge(challengeId, user);
+        assert.equal(
+          (await tokenBalance(user.publicKey)) - balanceBefore,
+          stake.toNumber() + reward
+        );
+      }
+
+      try {
+        await claimChallenge(challengeId, winner);
+
+        assert.fail("Should have failed because the winner already claimed");
+      } catch (error) {
+        assert.include(error.message, "AlreadyClaimed");
+      }
+
+      // the participant who quit already got their refund
+      for (const user of [quitter, expiredDisputer]) {
+        try {
+          await claimChallenge(challengeId, user);
+
+          assert.fail("Should have failed because the participant lost");
+        } catch (error) {
+          assert.include(error.message, "UserHasNotCompletedTheChallenge");
+        }
+      }
+    });
+
+    it("Collect the fees", async () => {
+      const feeRecipientBalanceBefore = await tokenBalance(
+        feeRecipient.publicKey
+      );
+      const creatorBalanceBefore = await tokenBalance(creator.publicKey);
+      await claimFees(challengeId);
+
+      const forfeitedPool = stake.toNumber() + stake.toNumber() / 2;
+      assert.equal(
+        (await tokenBalance(feeRecipient.publicKey)) -
+          feeRecipientBalanceBefore,
+        (forfeitedPool * platformFeeBps) / 10000
+      );
+      assert.equal(
+        (await tokenBalance(creator.publicKey)) - creatorBalanceBefore,
+        (forfeitedPool * creatorFeeBps) / 10000
+      );
+
+      try {
+        await claimFees(challengeId);
+
+        assert.fail("Should have failed because the fees are collected");
+      } catch (error) {
+        assert.include(error.message, "NoFeesToClaim");
+      }
+    });
+
+    it("Close the challenge once the open dispute expired", async () => {
+      // the bond of the open dispute would be stuck with the closed account
+      try {
+        await closeUserChallenge(challengeId, expiredDisputer);
+
+        assert.fail("Should have failed because the dispute is open");
+      } catch (error) {
+        assert.include(error.message, "ChallengeUnderDispute");
+      }
+
+      const lamportsBefore = await provider.connection.getBalance(
+        expiredDisputer.publicKey
+      );
+      await program.methods
+        .expireDispute(challengeId, expiredDisputer.publicKey)
+        .accounts({
+          signer: creator.publicKey,
+          user: expiredDisputer.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+      assert.isAtLeast(
+        (await provider.connection.getBalance(expiredDisputer.publicKey)) -
+          lamportsBefore,
+        DISPUTE_BOND
+      );
+
+      try {
+        await closeChallenge(challengeId, null);
+
+        assert.fail("Should have failed because participants are left");
+      } catch (error) {
+        assert.include(error.message, "ChallengeHasParticipants");
+      }
+
+      for (const user of [winner, upheldDisputer, quitter, expiredDisputer]) {
+        await closeUserChallenge(challengeId, user);
+      }
+      // everything was paid out, nothing is left for the fee recipient
+      const feeRecipientBalanceBefore = await tokenBalance(
+        feeRecipient.publicKey
+      );
+      await closeChallenge(challengeId, null);
+
+      assert.equal(
+        await tokenBalance(feeRecipient.publicKey),
+        feeRecipientBalanceBefore
+      );
+      assert.isNull(
+        await program.account.challengeAccount.fetchNullable(challengeAccount)
+      );
+    });
+  });
+
+  describe("a charity challenge nobody completes", () => {
+    const challengeId = new BN(101);
+    const challengeAccount = findChallengeAccount(challengeId);
+    const sponsorAmount = stake.toNumber() * 0.3;
+    let charityAta: PublicKey;
+    let endTime: number;
+    const disputeEndTime = () =>
+      endTime + VERIFICATION_DURATION + DISPUTE_DURATION;
+
+    it("Join and sponsor the challenge", async () => {
+      charityAta = getAssociatedTokenAddressSync(mint, charity.publicKey);
+      const startTime = (await clockTime()) + 8;
+      endTime = startTime + 4;
+      await initializeChallenge(
+        challengeId,
+        { googleFit: { steps: new BN(10000) } },
+        startTime,
+        endTime,
+        { ...defaultRules, claimDuration: new BN(MIN_CLAIM_DURATION) },
+        { beneficiary: charityAta }
+      );
+      await joinChallenge(challengeId, donor);
+      await program.methods
+        .sponsorChallenge(challengeId, new BN(sponsorAmount))
+        .accounts({
+          signer: sponsor.publicKey,
+          mint,
+          treasuryAccount: findTreasuryAccount(challengeId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sponsor])
+        .rpc();
+    });
+
+    it("Donate the forfeited pool and refund the sponsor", async () => {
+      await waitFor(disputeEndTime());
+      await settleParticipants(challengeId, [donor], null);
+      await program.methods
+        .finalizeChallenge(challengeId)
+        .accounts({
+          signer: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      const platformFee = (stake.toNumber() * platformFeeBps) / 10000;
+      const challengeData = await program.account.challengeAccount.fetch(
+        challengeAccount
+      );
+      assert.equal(challengeData.totalWinners.toNumber(), 0);
+      assert.equal(
+        challengeData.beneficiaryAmount.toNumber(),
+        stake.toNumber() - platformFee
+      );
+
+      try {
+        await claimChallenge(challengeId, donor);
+
+        assert.fail("Should have failed because the participant lost");
+      } catch (error) {
+        assert.include(error.message, "UserHasNotCompletedTheChallenge");
+      }
+
+      const charityBalanceBefore = await tokenBalance(charity.publicKey);
+      await program.methods
+        .sweepToBeneficiary(challengeId)
+        .accounts({
+          signer: donor.publicKey,
+          mint,
+          treasuryAccount: findTreasuryAccount(challengeId),
+          beneficiary: charityAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([donor])
+        .rpc();
+      assert.equal(
+        (await tokenBalance(charity.publicKey)) - charityBalanceBefore,
+        stake.toNumber() - platformFee
+      );
+
+      // nobody won the sponsorship, so it goes back to the sponsor
+      const sponsorBalanceBefore = await tokenBalance(sponsor.publicKey);
+      await program.methods
+        .refundSponsor(challengeId)
+        .accounts({
+          signer: sponsor.publicKey,
+          mint,
+          treasuryAccount: findTreasuryAccount(challengeId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sponsor])
+        .rpc();
+      assert.equal(
+        (await tokenBalance(sponsor.publicKey)) - sponsorBalanceBefore,
+        sponsorAmount
+      );
+      assert.isNull(
+        await program.account.sponsorAccount.fetchNullable(
+          findSponsorAccount(challengeId, sponsor.publicKey)
+        )
+      );
+    });
+
+    it("Sweep what is left once the claim window is over", async () => {
+      const sweepUnclaimed = async () =>
+        program.methods
+          .sweepUnclaimed(challengeId)
+          .accounts({
+            signer: donor.publicKey,
+            mint,
+            treasuryAccount: findTreasuryAccount(challengeId),
+            feeRecipient: feeRecipient.publicKey,
+            creator: creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([donor])
+          .rpc();
+
+      try {
+        await sweepUnclaimed();
+
+        assert.fail("Should have failed because the claim window is open");
+      } catch (error) {
+        assert.include(error.message, "ClaimDeadlineNotPassed");
+      }
+
+      await waitFor(disputeEndTime() + MIN_CLAIM_DURATION);
+      // the platform fee nobody collected goes to the fee recipient
+      const feeRecipientBalanceBefore = await tokenBalance(
+        feeRecipient.publicKey
+      );
+      await sweepUnclaimed();
+      assert.equal(
+        (await tokenBalance(feeRecipient.publicKey)) -
+          feeRecipientBalanceBefore,
+        (stake.toNumber() * platformFeeBps) / 10000
+      );
+      const challengeData = await program.account.challengeAccount.fetch(
+        challengeAccount
+      );
+      assert.equal(challengeData.isTreasuryClosed, true);
+
+      await closeUserChallenge(challengeId, donor);
+      await closeChallenge(challengeId, null);
+      assert.isNull(
+        await program.account.challengeAccount.fetchNullable(challengeAccount)
+      );
+    });
+  });
+
+  describe("a vote based challenge requiring a proof", () => {
+    const challengeId = new BN(102);
+    const challengeAccount = findChallengeAccount(challengeId);
+    const voteTallyAccount = findVoteTallyAccount(challengeId);
+    let endTime: number;
+    const verificationStartTime = () => endTime + PROOF_SUBMISSION_DURATION;
+    const verificationEndTime = () =>
+      verificationStartTime() + VERIFICATION_DURATION;
+    const disputeEndTime = () => verificationEndTime() + DISPUTE_DURATION;
+
+    it("Submit a proof and vote", async () => {
+      const startTime = (await clockTime()) + 8;
+      endTime = startTime + 4;
+      await initializeChallenge(
+        challengeId,
+        { voteBased: {} },
+        startTime,
+        endTime,
+        { ...defaultRules, requiresProof: true },
+        { voteTallyAccount }
+      );
+      for (const user of [prover, skipper]) {
+        await joinChallenge(challengeId, user);
+      }
+
+      await waitFor(endTime);
+      await program.methods
+        .submitProof(
+          challengeId,
+          Array.from(Buffer.alloc(32, 1)),
+          "https://example.com/proof"
+        )
+        .accounts({
+          signer: prover.publicKey,
+        })
+        .signers([prover])
+        .rpc();
+
+      await waitFor(verificationStartTime());
+      // nobody can vote for a participant who skipped the proof
+      try {
+        await program.methods
+          .voteForVoteBasedChallenge(challengeId, skipper.publicKey, {
+            voteBased: { isCompleted: true },
+          })
+          .accounts({
+            signer: prover.publicKey,
+            voter: prover.publicKey,
+            sessionKeyAccount: null,
+          })
+          .signers([prover])
+          .rpc();
+
+        assert.fail("Should have failed because no proof was submitted");
+      } catch (error) {
+        assert.include(error.message, "ProofNotSubmitted");
+      }
+
+      await program.methods
+        .voteForVoteBasedChallenge(challengeId, prover.publicKey, {
+          voteBased: { isCompleted: false },
+        })
+        .accounts({
+          signer: skipper.publicKey,
+          voter: skipper.publicKey,
+          sessionKeyAccount: null,
+        })
+        .signers([skipper])
+        .rpc();
+    });
+
+    it("Reject a dispute", async () => {
+      await waitFor(verificationEndTime());
+      await program.methods
+        .openDispute(challengeId)
+        .accounts({
+          signer: prover.publicKey,
+          voteTallyAccount,
+        })
+        .signers([prover])
+        .rpc();
+
+      // the bond of a rejected dispute goes to the owner, who pays the fee too
+      const ownerLamportsBefore = await provider.connection.getBalance(
+        owner.publicKey
+      );
+      await program.methods
+        .resolveDispute(challengeId, prover.publicKey, false)
+        .accounts({
+          signer: arbiter.publicKey,
+          user: prover.publicKey,
+          owner: owner.publicKey,
+        })
+        .signers([arbiter])
+        .rpc();
+      assert.approximately(
+        (await provider.connection.getBalance(owner.publicKey)) -
+          ownerLamportsBefore,
+        DISPUTE_BOND,
+        10000
+      );
+    });
+
+    it("Settle a challenge nobody won", async () => {
+      await waitFor(disputeEndTime());
+      await settleParticipants(
+        challengeId,
+        [prover, skipper],
+        voteTallyAccount
+      );
+
+      // the participant without a proof loses even without votes against them
+      for (const user of [prover, skipper]) {
+        const userChallengeData =
+          await program.account.userChallengeAccount.fetch(
+            findUserChallengeAccount(challengeId, user.publicKey)
+          );
+        assert.equal(userChallengeData.isChallengeCompleted, false);
+      }
+
+      await program.methods
+        .finalizeChallenge(challengeId)
+        .accounts({
+          signer: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+      const challengeData = await program.account.challengeAccount.fetch(
+        challengeAccount
+      );
+      assert.equal(challengeData.totalWinners.toNumber(), 0);
+      assert.equal(
+        challengeData.forfeitedPool.toNumber(),
+        2 * stake.toNumber()
+      );
+    });
+
+    it("Give what nobody won to the fee recipient on close", async () => {
+      const forfeitedPool = 2 * stake.toNumber();
+      const platformFee = (forfeitedPool * platformFeeBps) / 10000;
+      const feeRecipientBalanceBefore = await tokenBalance(
+        feeRecipient.publicKey
+      );
+      await claimFees(challengeId);
+      assert.equal(
+        (await tokenBalance(feeRecipient.publicKey)) -
+          feeRecipientBalanceBefore,
+        platformFee
+      );
+
+      for (const user of [prover, skipper]) {
+        await closeUserChallenge(challengeId, user);
+      }
+      // the creator never takes more than the creator fee
+      const creatorBalanceBefore = await tokenBalance(creator.publicKey);
+      await closeChallenge(challengeId, voteTallyAccount);
+
+      assert.equal(
+        (await tokenBalance(feeRecipient.publicKey)) -
+          feeRecipientBalanceBefore,
+        forfeitedPool
+      );
+      assert.equal(await tokenBalance(creator.publicKey), creatorBalanceBefore);
+      assert.isNull(
+        await program.account.challengeAccount.fetchNullable(challengeAccount)
+      );
+    });
+  });
+});